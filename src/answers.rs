@@ -0,0 +1,173 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use crate::runner::{PartResult, Status};
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::io::{self, ErrorKind};
+use std::path::PathBuf;
+
+/// Accepted answers of a day, stored in `src/answers/<year>/NN.json`.
+#[derive(Debug, Clone, Default, Eq, PartialEq, Serialize, Deserialize)]
+pub struct Answers {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub part_one: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub part_two: Option<String>,
+}
+
+impl Answers {
+    pub fn path(year: u16, day: u8) -> PathBuf {
+        crate::file_path("answers", year, day).with_extension("json")
+    }
+
+    /// Loads the answers of `day`. A missing file means nothing has been accepted yet.
+    pub fn load(year: u16, day: u8) -> io::Result<Self> {
+        match fs::read_to_string(Self::path(year, day)) {
+            Ok(contents) => serde_json::from_str(&contents)
+                .map_err(|e| io::Error::new(ErrorKind::InvalidData, e)),
+            Err(e) if e.kind() == ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(e),
+        }
+    }
+
+    pub fn save(&self, year: u16, day: u8) -> io::Result<()> {
+        let path = Self::path(year, day);
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+
+        let json = serde_json::to_string_pretty(self)?;
+        fs::write(path, json + "\n")
+    }
+
+    pub fn get(&self, part: u8) -> Option<&str> {
+        match part {
+            1 => self.part_one.as_deref(),
+            2 => self.part_two.as_deref(),
+            _ => None,
+        }
+    }
+
+    pub fn set(&mut self, part: u8, answer: String) {
+        match part {
+            1 => self.part_one = Some(answer),
+            2 => self.part_two = Some(answer),
+            _ => {}
+        }
+    }
+
+    /// Marks results that don't reproduce the accepted answer of their part, see
+    /// `PartResult::is_mismatch`. Solved results with a different answer and unsolved ones become
+    /// `Status::Wrong`, panicked and timed out ones keep their status and message.
+    pub fn verify(&self, results: &mut [PartResult]) {
+        for result in results {
+            result.expected = self.get(result.part).map(str::to_string);
+
+            let wrong = match (result.status, &result.answer, &result.expected) {
+                (Status::Solved, Some(answer), Some(expected)) => answer != expected,
+                (Status::Unsolved, _, Some(_)) => true,
+                _ => false,
+            };
+            if wrong {
+                result.status = Status::Wrong;
+            }
+        }
+    }
+
+    /// Accepts the answers of all solved `results`. Returns the parts that changed.
+    pub fn record(&mut self, results: &[PartResult]) -> Vec<u8> {
+        results
+            .iter()
+            .filter(|result| matches!(result.status, Status::Solved | Status::Wrong))
+            .filter_map(|result| {
+                let answer = result.answer.clone()?;
+                if self.get(result.part) == Some(answer.as_str()) {
+                    return None;
+                }
+
+                self.set(result.part, answer);
+                Some(result.part)
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn result(part: u8, answer: Option<&str>, status: Status) -> PartResult {
+        PartResult {
+            answer: answer.map(str::to_string),
+            status,
+            ..PartResult::unsolved(2022, 1, part)
+        }
+    }
+
+    #[test]
+    fn test_verify() {
+        let answers = Answers {
+            part_one: Some("24000".into()),
+            part_two: Some("45000".into()),
+        };
+
+        let mut results = vec![
+            result(1, Some("24000"), Status::Solved),
+            result(2, Some("41000"), Status::Solved),
+        ];
+        answers.verify(&mut results);
+
+        assert_eq!(results[0].status, Status::Solved);
+        assert_eq!(results[1].status, Status::Wrong);
+        assert_eq!(results[1].expected.as_deref(), Some("45000"));
+        assert!(results[1].is_mismatch());
+    }
+
+    #[test]
+    fn test_verify_failures() {
+        let answers = Answers {
+            part_one: Some("24000".into()),
+            part_two: None,
+        };
+
+        let mut results = vec![
+            PartResult::panicked(2022, 1, 1, "attempt to divide by zero".into()),
+            result(2, None, Status::Unsolved),
+        ];
+        answers.verify(&mut results);
+
+        assert_eq!(results[0].status, Status::Panicked);
+        assert!(results[0].is_mismatch());
+        assert!(!results[1].is_mismatch());
+
+        let mut unsolved = vec![result(1, None, Status::Unsolved)];
+        answers.verify(&mut unsolved);
+        assert_eq!(unsolved[0].status, Status::Wrong);
+        assert!(unsolved[0].is_mismatch());
+    }
+
+    #[test]
+    fn test_record() {
+        let mut answers = Answers {
+            part_one: Some("24000".into()),
+            part_two: None,
+        };
+
+        let results = vec![
+            result(1, Some("24000"), Status::Solved),
+            result(2, Some("45000"), Status::Solved),
+        ];
+
+        assert_eq!(answers.record(&results), vec![2]);
+        assert_eq!(answers.get(2), Some("45000"));
+        assert_eq!(
+            serde_json::to_string(&answers).unwrap(),
+            r#"{"part_one":"24000","part_two":"45000"}"#
+        );
+
+        let unsolved = vec![result(1, None, Status::Unsolved)];
+        assert!(answers.record(&unsolved).is_empty());
+    }
+}
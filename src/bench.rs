@@ -0,0 +1,207 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use crate::runner::{catch_silently, serialize_nanos};
+use serde::Serialize;
+use std::fmt;
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+const DEFAULT_ITERATIONS: u32 = 100;
+const DEFAULT_WARMUP: u32 = 3;
+const DEFAULT_BUDGET: Duration = Duration::from_secs(10);
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct BenchOptions {
+    /// Measured runs per part, not counting warm-up.
+    pub iterations: u32,
+    /// Runs that are executed but discarded before measuring.
+    pub warmup: u32,
+    /// Measuring stops early once a part has used up this much time.
+    pub budget: Duration,
+}
+
+impl Default for BenchOptions {
+    fn default() -> Self {
+        Self {
+            iterations: DEFAULT_ITERATIONS,
+            warmup: DEFAULT_WARMUP,
+            budget: DEFAULT_BUDGET,
+        }
+    }
+}
+
+impl BenchOptions {
+    /// Parses `--bench`, `--iterations N`, `--warmup N` and `--budget-ms N`.
+    /// Returns `None` unless at least one of them is given.
+    pub fn from_args(args: &mut pico_args::Arguments) -> Result<Option<Self>, pico_args::Error> {
+        let enabled = args.contains("--bench");
+        let iterations: Option<u32> = args.opt_value_from_str("--iterations")?;
+        let warmup: Option<u32> = args.opt_value_from_str("--warmup")?;
+        let budget: Option<u64> = args.opt_value_from_str("--budget-ms")?;
+
+        if !enabled && iterations.is_none() && warmup.is_none() && budget.is_none() {
+            return Ok(None);
+        }
+
+        let defaults = Self::default();
+        Ok(Some(Self {
+            // a budget on its own means "as many runs as fit".
+            iterations: match (iterations, budget) {
+                (Some(iterations), _) => iterations.max(1),
+                (None, Some(_)) => u32::MAX,
+                (None, None) => defaults.iterations,
+            },
+            warmup: warmup.unwrap_or(defaults.warmup),
+            budget: budget.map(Duration::from_millis).unwrap_or(defaults.budget),
+        }))
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Serialize)]
+pub struct Stats {
+    pub samples: usize,
+    #[serde(rename = "min_ns", serialize_with = "serialize_nanos")]
+    pub min: Duration,
+    #[serde(rename = "median_ns", serialize_with = "serialize_nanos")]
+    pub median: Duration,
+    #[serde(rename = "mean_ns", serialize_with = "serialize_nanos")]
+    pub mean: Duration,
+    #[serde(rename = "p95_ns", serialize_with = "serialize_nanos")]
+    pub p95: Duration,
+    #[serde(rename = "std_dev_ns", serialize_with = "serialize_nanos")]
+    pub std_dev: Duration,
+}
+
+impl Stats {
+    pub fn from_samples(samples: &[Duration]) -> Option<Self> {
+        if samples.is_empty() {
+            return None;
+        }
+
+        let mut sorted = samples.to_vec();
+        sorted.sort_unstable();
+
+        let n = sorted.len();
+        let median = if n.is_multiple_of(2) {
+            (sorted[n / 2 - 1] + sorted[n / 2]) / 2
+        } else {
+            sorted[n / 2]
+        };
+        // nearest-rank percentile
+        let p95 = sorted[((n as f64 * 0.95).ceil() as usize).max(1) - 1];
+
+        let nanos: Vec<f64> = sorted.iter().map(|d| d.as_nanos() as f64).collect();
+        let mean = nanos.iter().sum::<f64>() / n as f64;
+        let variance = if n > 1 {
+            nanos.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / (n - 1) as f64
+        } else {
+            0_f64
+        };
+
+        Some(Self {
+            samples: n,
+            min: sorted[0],
+            median,
+            mean: Duration::from_nanos(mean.round() as u64),
+            p95,
+            std_dev: Duration::from_nanos(variance.sqrt().round() as u64),
+        })
+    }
+
+    /// Combines the stats of independently measured parts, e.g. for the totals of `cargo all`.
+    /// Standard deviations are added as variances.
+    pub fn total<'a>(stats: impl IntoIterator<Item = &'a Stats>) -> Option<Self> {
+        stats.into_iter().fold(None, |acc: Option<Self>, stats| {
+            let Some(acc) = acc else {
+                return Some(*stats);
+            };
+
+            let variance = acc.std_dev.as_secs_f64().powi(2) + stats.std_dev.as_secs_f64().powi(2);
+            Some(Self {
+                samples: acc.samples + stats.samples,
+                min: acc.min + stats.min,
+                median: acc.median + stats.median,
+                mean: acc.mean + stats.mean,
+                p95: acc.p95 + stats.p95,
+                std_dev: Duration::from_secs_f64(variance.sqrt()),
+            })
+        })
+    }
+}
+
+impl fmt::Display for Stats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} runs, min: {:.2?}, median: {:.2?}, mean: {:.2?}, p95: {:.2?}, σ: {:.2?}",
+            self.samples, self.min, self.median, self.mean, self.p95, self.std_dev
+        )
+    }
+}
+
+/// Runs `solver` repeatedly according to `options`.
+/// Returns `None` if the solver panics or doesn't produce an answer.
+pub fn bench_part<T>(solver: impl Fn() -> Option<T>, options: &BenchOptions) -> Option<Stats> {
+    let run = || catch_silently(|| black_box(solver())).ok().flatten();
+
+    for _ in 0..options.warmup {
+        run()?;
+    }
+
+    let started = Instant::now();
+    let mut samples = Vec::new();
+
+    while samples.len() < options.iterations as usize {
+        let timer = Instant::now();
+        run()?;
+        samples.push(timer.elapsed());
+
+        if started.elapsed() >= options.budget {
+            break;
+        }
+    }
+
+    Stats::from_samples(&samples)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn micros(values: &[u64]) -> Vec<Duration> {
+        values.iter().map(|&v| Duration::from_micros(v)).collect()
+    }
+
+    #[test]
+    fn test_from_samples() {
+        let stats = Stats::from_samples(&micros(&[5, 1, 4, 2, 3])).unwrap();
+        assert_eq!(stats.samples, 5);
+        assert_eq!(stats.min, Duration::from_micros(1));
+        assert_eq!(stats.median, Duration::from_micros(3));
+        assert_eq!(stats.mean, Duration::from_micros(3));
+        assert_eq!(stats.p95, Duration::from_micros(5));
+        assert_eq!(stats.std_dev, Duration::from_nanos(1581));
+
+        let stats = Stats::from_samples(&micros(&[4, 2])).unwrap();
+        assert_eq!(stats.median, Duration::from_micros(3));
+
+        assert_eq!(Stats::from_samples(&[]), None);
+    }
+
+    #[test]
+    fn test_total() {
+        let a = Stats::from_samples(&micros(&[1, 3])).unwrap();
+        let b = Stats::from_samples(&micros(&[10, 30])).unwrap();
+        let total = Stats::total([&a, &b]).unwrap();
+
+        assert_eq!(total.samples, 4);
+        assert_eq!(total.min, Duration::from_micros(11));
+        assert_eq!(total.median, Duration::from_micros(22));
+        assert_eq!(total.mean, Duration::from_micros(22));
+        assert_eq!(total.p95, Duration::from_micros(33));
+
+        assert_eq!(Stats::total([]), None);
+    }
+}
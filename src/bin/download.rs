@@ -0,0 +1,287 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use advent_of_code::{bin_name, default_year, file_path};
+use anyhow::{anyhow, bail, Context, Result};
+use std::{
+    env,
+    fs::{self, OpenOptions},
+    io::{ErrorKind, Write},
+    path::{Path, PathBuf},
+    process,
+};
+
+const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+const USER_AGENT: &str = "github.com/Olaroll/advent-of-code-2022";
+
+const SESSION_ENV: &str = "AOC_SESSION";
+const SESSION_FILE_ENV: &str = "AOC_SESSION_FILE";
+const BASE_URL_ENV: &str = "AOC_BASE_URL";
+const SESSION_FILE_NAME: &str = ".adventofcode.session";
+
+struct Args {
+    day: u8,
+    year: u16,
+    base_url: String,
+    force: bool,
+    description: bool,
+}
+
+fn parse_args() -> Result<Args, pico_args::Error> {
+    let mut args = pico_args::Arguments::from_env();
+
+    let force = args.contains(["-f", "--force"]);
+    let description = args.contains(["-d", "--description"]);
+    let year = args.opt_value_from_str("--year")?.unwrap_or_else(default_year);
+    let base_url = args
+        .opt_value_from_str("--base-url")?
+        .or_else(|| env::var(BASE_URL_ENV).ok())
+        .unwrap_or_else(|| DEFAULT_BASE_URL.to_string());
+    let day = args.free_from_str()?;
+
+    Ok(Args {
+        day,
+        year,
+        base_url,
+        force,
+        description,
+    })
+}
+
+/// Reads the session token from `AOC_SESSION`, falling back to the file at `AOC_SESSION_FILE`
+/// or `~/.adventofcode.session` (the same file `aoc-cli` uses).
+fn read_session() -> Result<String> {
+    if let Ok(session) = env::var(SESSION_ENV) {
+        if !session.trim().is_empty() {
+            return Ok(session.trim().to_string());
+        }
+    }
+
+    let path = match env::var_os(SESSION_FILE_ENV) {
+        Some(path) => PathBuf::from(path),
+        None => env::var_os("HOME")
+            .or_else(|| env::var_os("USERPROFILE"))
+            .map(|home| Path::new(&home).join(SESSION_FILE_NAME))
+            .ok_or_else(|| anyhow!("could not determine the home directory"))?,
+    };
+
+    let session = fs::read_to_string(&path).with_context(|| {
+        format!(
+            "no session token: set {} or put it in \"{}\"",
+            SESSION_ENV,
+            path.display()
+        )
+    })?;
+
+    match session.trim() {
+        "" => bail!("session file \"{}\" is empty", path.display()),
+        session => Ok(session.to_string()),
+    }
+}
+
+fn fetch(url: &str, session: &str) -> Result<String> {
+    let response = ureq::get(url)
+        .set("Cookie", &format!("session={}", session))
+        .set("User-Agent", USER_AGENT)
+        .call();
+
+    match response {
+        Ok(response) => Ok(response.into_string()?),
+        Err(ureq::Error::Status(400, _)) => bail!("server rejected the session token ({})", url),
+        Err(ureq::Error::Status(404, _)) => bail!("puzzle is not available yet ({})", url),
+        Err(e) => Err(anyhow!(e).context(format!("request to {} failed", url))),
+    }
+}
+
+fn fetch_input(base_url: &str, year: u16, day: u8, session: &str) -> Result<String> {
+    let url = format!("{}/{}/day/{}/input", base_url.trim_end_matches('/'), year, day);
+    fetch(&url, session)
+}
+
+fn fetch_description(base_url: &str, year: u16, day: u8, session: &str) -> Result<String> {
+    let url = format!("{}/{}/day/{}", base_url.trim_end_matches('/'), year, day);
+    let page = fetch(&url, session)?;
+
+    let articles: Vec<_> = page
+        .match_indices("<article")
+        .filter_map(|(start, _)| {
+            let len = page[start..].find("</article>")?;
+            Some(&page[start..start + len + "</article>".len()])
+        })
+        .collect();
+
+    if articles.is_empty() {
+        bail!("couldn't find the puzzle description in {}", url);
+    }
+
+    Ok(articles.join("\n"))
+}
+
+/// Like `safe_create_file` in `scaffold.rs`, but empty files (as created by the scaffold) may be replaced.
+fn safe_write_file(path: &Path, contents: &str, force: bool) -> Result<(), std::io::Error> {
+    let is_empty = match fs::metadata(path) {
+        Ok(metadata) => metadata.len() == 0,
+        Err(e) if e.kind() == ErrorKind::NotFound => true,
+        Err(e) => return Err(e),
+    };
+
+    if !is_empty && !force {
+        return Err(std::io::Error::new(
+            ErrorKind::AlreadyExists,
+            "file is not empty, use `--force` to overwrite it",
+        ));
+    }
+
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+
+    OpenOptions::new()
+        .write(true)
+        .create(true)
+        .truncate(true)
+        .open(path)?
+        .write_all(contents.as_bytes())
+}
+
+fn main() {
+    let args = match parse_args() {
+        Ok(args) => args,
+        Err(_) => {
+            eprintln!("Need to specify a day (as integer). example: `cargo download 7`");
+            process::exit(1);
+        }
+    };
+
+    let session = match read_session() {
+        Ok(session) => session,
+        Err(e) => {
+            eprintln!("Failed to read session token: {:#}", e);
+            process::exit(1);
+        }
+    };
+
+    let input_path = file_path("inputs", args.year, args.day);
+
+    let input = match fetch_input(&args.base_url, args.year, args.day, &session) {
+        Ok(input) => input,
+        Err(e) => {
+            eprintln!("Failed to download input: {:#}", e);
+            process::exit(1);
+        }
+    };
+
+    match safe_write_file(&input_path, &input, args.force) {
+        Ok(_) => {
+            println!("Downloaded input file \"{}\"", input_path.display());
+        }
+        Err(e) => {
+            eprintln!("Failed to write input file \"{}\": {}", input_path.display(), e);
+            process::exit(1);
+        }
+    }
+
+    if args.description {
+        let puzzle_path = file_path("puzzles", args.year, args.day).with_extension("html");
+
+        let description = match fetch_description(&args.base_url, args.year, args.day, &session) {
+            Ok(description) => description,
+            Err(e) => {
+                eprintln!("Failed to download puzzle description: {:#}", e);
+                process::exit(1);
+            }
+        };
+
+        match safe_write_file(&puzzle_path, &description, args.force) {
+            Ok(_) => {
+                println!("Downloaded puzzle description \"{}\"", puzzle_path.display());
+            }
+            Err(e) => {
+                eprintln!(
+                    "Failed to write puzzle description \"{}\": {}",
+                    puzzle_path.display(),
+                    e
+                );
+                process::exit(1);
+            }
+        }
+    }
+
+    println!("---");
+    println!(
+        "🎄 Type `cargo solve {}` to run your solution.",
+        bin_name(args.year, args.day)
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::{BufRead, BufReader};
+    use std::net::TcpListener;
+    use std::thread;
+
+    /// Serves a single request with `body` and returns the base url plus the received request head.
+    fn mock_server(body: &'static str) -> (String, thread::JoinHandle<Vec<String>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+
+        let handle = thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+
+            let head: Vec<String> = BufReader::new(&stream)
+                .lines()
+                .map(|line| line.unwrap())
+                .take_while(|line| !line.is_empty())
+                .collect();
+
+            write!(
+                stream,
+                "HTTP/1.1 200 OK\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                body.len(),
+                body
+            )
+            .unwrap();
+
+            head
+        });
+
+        (base_url, handle)
+    }
+
+    #[test]
+    fn test_fetch_input() {
+        let (base_url, server) = mock_server("1000\n2000\n");
+        let input = fetch_input(&base_url, 2022, 1, "abc123").unwrap();
+        let head = server.join().unwrap();
+
+        assert_eq!(input, "1000\n2000\n");
+        assert_eq!(head[0], "GET /2022/day/1/input HTTP/1.1");
+        assert!(head.iter().any(|line| line == "Cookie: session=abc123"));
+    }
+
+    #[test]
+    fn test_fetch_description() {
+        let (base_url, server) = mock_server(
+            "<html><main><article class=\"day-desc\"><h2>Day 1</h2></article><p>x</p></main></html>",
+        );
+        let description = fetch_description(&base_url, 2022, 1, "abc123").unwrap();
+        server.join().unwrap();
+
+        assert_eq!(description, "<article class=\"day-desc\"><h2>Day 1</h2></article>");
+    }
+
+    #[test]
+    fn test_safe_write_file() {
+        let path = env::temp_dir().join(format!("aoc-download-test-{}.txt", process::id()));
+        fs::write(&path, "").unwrap();
+
+        assert!(safe_write_file(&path, "first", false).is_ok());
+        assert!(safe_write_file(&path, "second", false).is_err());
+        assert!(safe_write_file(&path, "third", true).is_ok());
+        assert_eq!(fs::read_to_string(&path).unwrap(), "third");
+
+        fs::remove_file(&path).unwrap();
+    }
+}
@@ -0,0 +1,427 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use advent_of_code::{default_year, FIRST_YEAR};
+use serde::{Deserialize, Serialize};
+use std::{
+    fs,
+    io::ErrorKind,
+    process,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+const JOURNAL_PATH: &str = "journal.json";
+const README_PATH: &str = "README.md";
+
+const USAGE: &str = "Usage: `cargo journal start <day>`, `cargo journal done <day> <part>`, \
+`cargo journal note <day> <text>` or `cargo journal render`, all with an optional `--year <year>`";
+
+/// Solving times and notes of one day. Times are kept as durations, so entries imported from
+/// the README and tracked ones look the same.
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+struct Entry {
+    year: u16,
+    day: u8,
+    /// When `start` was run, in seconds since the unix epoch.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    started: Option<u64>,
+    /// Seconds from the start until part 1 was done.
+    #[serde(default)]
+    part_one: Option<u64>,
+    /// Seconds from part 1 until part 2 was done.
+    #[serde(default)]
+    part_two: Option<u64>,
+    #[serde(default)]
+    notes: Vec<String>,
+}
+
+impl Entry {
+    fn new(year: u16, day: u8) -> Self {
+        Self {
+            year,
+            day,
+            started: None,
+            part_one: None,
+            part_two: None,
+            notes: Vec::new(),
+        }
+    }
+
+    fn heading(&self) -> String {
+        match self.year {
+            FIRST_YEAR => format!("### Day {:02}", self.day),
+            year => format!("### {} Day {:02}", year, self.day),
+        }
+    }
+
+    /// Stamps `part` as done at `now`.
+    fn done(&mut self, part: u8, now: u64) -> Result<(), String> {
+        let started = self.started.ok_or_else(|| {
+            format!(
+                "day {} was not started, run `cargo journal start {}` first",
+                self.day, self.day
+            )
+        })?;
+
+        match (part, self.part_one, self.part_two) {
+            (1, None, _) => self.part_one = Some(now.saturating_sub(started)),
+            (2, Some(part_one), None) => {
+                self.part_two = Some(now.saturating_sub(started + part_one))
+            }
+            (2, None, _) => return Err(format!("part 1 of day {} is not done yet", self.day)),
+            (1 | 2, _, _) => {
+                return Err(format!("part {} of day {} is done already", part, self.day))
+            }
+            _ => return Err(format!("unknown part {}, expected 1 or 2", part)),
+        }
+
+        Ok(())
+    }
+}
+
+/// Everything the journal section of the README is generated from, stored in `journal.json`.
+#[derive(Debug, Clone, Default, Eq, PartialEq, Serialize, Deserialize)]
+struct Journal {
+    /// Lines between the `## Journal` heading and the first day.
+    preamble: Vec<String>,
+    entries: Vec<Entry>,
+}
+
+fn format_duration(seconds: Option<u64>) -> String {
+    match seconds {
+        Some(seconds) => format!(
+            "{:02}:{:02}:{:02}",
+            seconds / 3600,
+            seconds / 60 % 60,
+            seconds % 60
+        ),
+        None => "--:--:--".to_string(),
+    }
+}
+
+fn parse_duration(s: &str) -> Option<u64> {
+    let mut fields = s.split(':').map(|field| field.parse::<u64>().ok());
+    match (
+        fields.next()?,
+        fields.next()?,
+        fields.next()?,
+        fields.next(),
+    ) {
+        (Some(hours), Some(minutes), Some(seconds), None) => {
+            Some(hours * 3600 + minutes * 60 + seconds)
+        }
+        _ => None,
+    }
+}
+
+/// Parses `### Day 9` or `### 2023 Day 09`.
+fn parse_heading(line: &str) -> Option<(u16, u8)> {
+    let heading = line.strip_prefix("### ")?.trim();
+    let (year, day) = match heading.split_once(" Day ") {
+        Some((year, day)) => (year.parse().ok()?, day),
+        None => (FIRST_YEAR, heading.strip_prefix("Day ")?),
+    };
+    Some((year, day.parse().ok()?))
+}
+
+/// Start and end of the journal section: from `## Journal` up to the next `## ` heading.
+fn journal_section(readme: &str) -> Result<(usize, usize), String> {
+    let start = readme
+        .find("## Journal")
+        .ok_or("couldn't find the `## Journal` section")?;
+    let end = readme[start + 1..]
+        .find("\n## ")
+        .map_or(readme.len(), |end| start + 1 + end + 1);
+    Ok((start, end))
+}
+
+impl Journal {
+    /// Reads the journal from the README, to start tracking on top of the hand-written one.
+    fn import(readme: &str) -> Result<Self, String> {
+        let (start, end) = journal_section(readme)?;
+        let mut journal = Self::default();
+
+        for line in readme[start..end].lines().skip(1) {
+            if let Some((year, day)) = parse_heading(line) {
+                journal.entries.push(Entry::new(year, day));
+                continue;
+            }
+
+            let Some(entry) = journal.entries.last_mut() else {
+                journal.preamble.push(line.to_string());
+                continue;
+            };
+
+            if let Some(times) = line.strip_prefix("#### Part 1: ") {
+                let (one, two) = times.split_once(" --> Part 2: ").unwrap_or((times, ""));
+                entry.part_one = parse_duration(one.trim());
+                entry.part_two = parse_duration(two.trim());
+            } else if let Some(note) = line.strip_prefix("- ") {
+                entry.notes.push(note.to_string());
+            }
+        }
+
+        while journal.preamble.last().is_some_and(|line| line.is_empty()) {
+            journal.preamble.pop();
+        }
+
+        Ok(journal)
+    }
+
+    fn entry(&mut self, year: u16, day: u8) -> &mut Entry {
+        let index = match self
+            .entries
+            .binary_search_by_key(&(year, day), |entry| (entry.year, entry.day))
+        {
+            Ok(index) => index,
+            Err(index) => {
+                self.entries.insert(index, Entry::new(year, day));
+                index
+            }
+        };
+        &mut self.entries[index]
+    }
+
+    /// Adds the days of `other` this journal doesn't have yet, like the stubs `cargo scaffold`
+    /// adds to the README. Days it has already keep their own times and notes.
+    fn merge_missing(&mut self, other: Journal) {
+        for entry in other.entries {
+            let (year, day) = (entry.year, entry.day);
+            if let Err(index) = self
+                .entries
+                .binary_search_by_key(&(year, day), |entry| (entry.year, entry.day))
+            {
+                self.entries.insert(index, entry);
+            }
+        }
+    }
+
+    fn render(&self) -> String {
+        let mut section = String::from("## Journal\n");
+        for line in &self.preamble {
+            section.push_str(line);
+            section.push('\n');
+        }
+
+        let entries: Vec<String> = self
+            .entries
+            .iter()
+            .map(|entry| {
+                let mut lines = vec![
+                    entry.heading(),
+                    format!(
+                        "#### Part 1: {} --> Part 2: {}",
+                        format_duration(entry.part_one),
+                        format_duration(entry.part_two)
+                    ),
+                ];
+                lines.extend(entry.notes.iter().map(|note| format!("- {}", note)));
+                lines.join("\n") + "\n"
+            })
+            .collect();
+
+        section + &entries.join("\n")
+    }
+
+    /// Replaces the journal section of `readme`, leaving everything around it untouched.
+    fn update_readme(&self, readme: &str) -> Result<String, String> {
+        let (start, end) = journal_section(readme)?;
+        let separator = match end < readme.len() {
+            true => "\n",
+            false => "",
+        };
+
+        Ok(format!(
+            "{}{}{}{}",
+            &readme[..start],
+            self.render(),
+            separator,
+            &readme[end..]
+        ))
+    }
+}
+
+/// Loads `journal.json`, picking up days that were only added to the README since.
+/// Without `journal.json`, the README journal is imported as a whole.
+fn load() -> Result<Journal, String> {
+    let readme = fs::read_to_string(README_PATH)
+        .map_err(|e| format!("could not read \"{}\": {}", README_PATH, e))?;
+    let imported = Journal::import(&readme)?;
+
+    match fs::read_to_string(JOURNAL_PATH) {
+        Ok(contents) => {
+            let mut journal: Journal = serde_json::from_str(&contents)
+                .map_err(|e| format!("could not parse \"{}\": {}", JOURNAL_PATH, e))?;
+            journal.merge_missing(imported);
+            Ok(journal)
+        }
+        Err(e) if e.kind() == ErrorKind::NotFound => Ok(imported),
+        Err(e) => Err(format!("could not read \"{}\": {}", JOURNAL_PATH, e)),
+    }
+}
+
+fn save(journal: &Journal) -> Result<(), String> {
+    let json = serde_json::to_string_pretty(journal).map_err(|e| e.to_string())?;
+    fs::write(JOURNAL_PATH, json + "\n")
+        .map_err(|e| format!("could not write \"{}\": {}", JOURNAL_PATH, e))?;
+
+    let readme = fs::read_to_string(README_PATH)
+        .map_err(|e| format!("could not read \"{}\": {}", README_PATH, e))?;
+    fs::write(README_PATH, journal.update_readme(&readme)?)
+        .map_err(|e| format!("could not write \"{}\": {}", README_PATH, e))
+}
+
+fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |elapsed| elapsed.as_secs())
+}
+
+fn run() -> Result<String, String> {
+    let mut args = pico_args::Arguments::from_env();
+    let year: u16 = args
+        .opt_value_from_str("--year")
+        .map_err(|e| e.to_string())?
+        .unwrap_or_else(default_year);
+    let command = args.subcommand().map_err(|e| e.to_string())?;
+
+    let mut free = |name: &str| -> Result<String, String> {
+        args.opt_free_from_str()
+            .map_err(|e| e.to_string())?
+            .ok_or_else(|| format!("missing <{}>", name))
+    };
+    let mut day = || -> Result<u8, String> {
+        free("day")?
+            .parse()
+            .map_err(|_| "invalid <day>".to_string())
+    };
+
+    let mut journal = load()?;
+    let message = match command.as_deref() {
+        Some("start") => {
+            let day = day()?;
+            let entry = journal.entry(year, day);
+            if entry.started.is_some() || entry.part_one.is_some() {
+                return Err(format!("day {} was started already", day));
+            }
+            entry.started = Some(now());
+            format!("Started day {}, good luck!", day)
+        }
+        Some("done") => {
+            let day = day()?;
+            let part: u8 = free("part")?
+                .parse()
+                .map_err(|_| "invalid <part>".to_string())?;
+            let entry = journal.entry(year, day);
+            entry.done(part, now())?;
+            let elapsed = match part {
+                1 => entry.part_one,
+                _ => entry.part_two,
+            };
+            format!(
+                "Day {} part {} done in {}.",
+                day,
+                part,
+                format_duration(elapsed)
+            )
+        }
+        Some("note") => {
+            let day = day()?;
+            let note = free("text")?;
+            journal.entry(year, day).notes.push(note);
+            format!("Added a note to day {}.", day)
+        }
+        Some("render") => format!("Rendered the journal into \"{}\".", README_PATH),
+        _ => return Err("unknown command".to_string()),
+    };
+
+    if let Some(arg) = args.finish().first() {
+        return Err(format!("unexpected argument {:?}", arg));
+    }
+
+    save(&journal)?;
+    Ok(message)
+}
+
+fn main() {
+    match run() {
+        Ok(message) => println!("{}", message),
+        Err(e) => {
+            eprintln!("Failed to update the journal: {}", e);
+            eprintln!("{}", USAGE);
+            process::exit(1);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const README: &str = "# AoC\n\n## Journal\n###### Times are relative.\n### Day 01\n\
+        #### Part 1: 00:40:46 --> Part 2: 00:09:12\n- Chunks.\n- Iterators.\n\n\
+        ### Day 02\n#### Part 1: 12:11:52 --> Part 2: --:--:--\n\n## License\nMIT\n";
+
+    #[test]
+    fn test_import_render() {
+        let journal = Journal::import(README).unwrap();
+
+        assert_eq!(journal.preamble, ["###### Times are relative."]);
+        assert_eq!(journal.entries.len(), 2);
+        assert_eq!(journal.entries[0].part_one, Some(40 * 60 + 46));
+        assert_eq!(journal.entries[0].notes, ["Chunks.", "Iterators."]);
+        assert_eq!(journal.entries[1].part_two, None);
+
+        assert_eq!(journal.update_readme(README).unwrap(), README);
+
+        let readme = README.replace("### Day 02", "### Day 2");
+        assert_eq!(journal.update_readme(&readme).unwrap(), README);
+    }
+
+    #[test]
+    fn test_done() {
+        let mut journal = Journal::import(README).unwrap();
+
+        let entry = journal.entry(2023, 5);
+        assert!(entry.done(1, 100).is_err());
+
+        entry.started = Some(100);
+        assert!(entry.done(2, 200).is_err());
+        entry.done(1, 3700).unwrap();
+        assert!(entry.done(1, 3800).is_err());
+        entry.done(2, 3760).unwrap();
+        assert_eq!((entry.part_one, entry.part_two), (Some(3600), Some(60)));
+
+        assert!(journal
+            .render()
+            .ends_with("### 2023 Day 05\n#### Part 1: 01:00:00 --> Part 2: 00:01:00\n"));
+    }
+
+    #[test]
+    fn test_merge_missing() {
+        let mut journal = Journal::import(README).unwrap();
+        journal.entries.truncate(1);
+        journal.entries[0].notes.push("Tracked.".to_string());
+
+        let scaffolded = README.replace("\n\n## License", "\n### Day 03\n\n## License");
+        journal.merge_missing(Journal::import(&scaffolded).unwrap());
+
+        let days: Vec<u8> = journal.entries.iter().map(|entry| entry.day).collect();
+        assert_eq!(days, [1, 2, 3]);
+        assert_eq!(
+            journal.entries[0].notes,
+            ["Chunks.", "Iterators.", "Tracked."]
+        );
+        assert_eq!(journal.entries[2].part_one, None);
+    }
+
+    #[test]
+    fn test_parse_heading() {
+        assert_eq!(parse_heading("### Day 9"), Some((FIRST_YEAR, 9)));
+        assert_eq!(parse_heading("### 2023 Day 03"), Some((2023, 3)));
+        assert_eq!(parse_heading("###### All times are relative"), None);
+        assert_eq!(parse_duration("102:03:04"), Some(102 * 3600 + 3 * 60 + 4));
+        assert_eq!(parse_duration("--:--:--"), None);
+    }
+}
@@ -2,113 +2,531 @@
  * This file contains template code.
  * There is no need to edit this file unless you want to change template functionality.
  */
+use advent_of_code::{bin_name, default_year, FIRST_YEAR};
 use std::{
-    fs::{File, OpenOptions},
-    io::Write,
+    fs::{self, File, OpenOptions},
+    io::{ErrorKind, Write},
+    path::Path,
     process,
 };
 
-const MODULE_TEMPLATE: &str = r###"pub fn part_one(input: &str) -> Option<u32> {
-    None
-}
+const DEFAULT_ANSWER: &str = "u32";
+const TEMPLATES_DIR: &str = "templates";
+const YEARS_PATH: &str = "src/days/mod.rs";
+const README_PATH: &str = "README.md";
 
-pub fn part_two(input: &str) -> Option<u32> {
-    None
-}
+/// Expected answers of the example, checked by `examples::tests::test_examples` once filled in.
+const EXAMPLE_ANSWERS_STUB: &str = "{\n  \"part_one\": null,\n  \"part_two\": null\n}\n";
 
-fn main() {
-    let input = &advent_of_code::read_file("inputs", DAY);
-    advent_of_code::solve!(1, part_one, input);
-    advent_of_code::solve!(2, part_two, input);
+const USAGE: &str = "Usage: `cargo scaffold <day> [--year <year>] [--title <title>] \
+[--answer <type>] [--answer-one <type>] [--answer-two <type>] [--parsers] [--input-struct] [--dry-run]`";
+
+struct Args {
+    day: u8,
+    year: u16,
+    title: Option<String>,
+    answer_one: String,
+    answer_two: String,
+    /// Add a nom `parsers` submodule, like days 14, 15 and 16 have.
+    parsers: bool,
+    /// Parse into an `Input` struct that both parts share.
+    input_struct: bool,
+    dry_run: bool,
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+fn parse_args() -> Result<Args, pico_args::Error> {
+    let mut args = pico_args::Arguments::from_env();
 
-    #[test]
-    fn test_part_one() {
-        let input = advent_of_code::read_file("examples", DAY);
-        assert_eq!(part_one(&input), None);
+    let answer: Option<String> = args.opt_value_from_str("--answer")?;
+    let answer = answer.as_deref().unwrap_or(DEFAULT_ANSWER);
+
+    let parsed = Args {
+        year: args
+            .opt_value_from_str("--year")?
+            .unwrap_or_else(default_year),
+        title: args.opt_value_from_str("--title")?,
+        answer_one: args
+            .opt_value_from_str("--answer-one")?
+            .unwrap_or_else(|| answer.to_string()),
+        answer_two: args
+            .opt_value_from_str("--answer-two")?
+            .unwrap_or_else(|| answer.to_string()),
+        parsers: args.contains("--parsers"),
+        input_struct: args.contains("--input-struct"),
+        dry_run: args.contains("--dry-run"),
+        day: args.free_from_str()?,
+    };
+
+    match args.finish().first() {
+        Some(arg) => Err(pico_args::Error::ArgumentParsingFailed {
+            cause: format!("unexpected argument {:?}", arg),
+        }),
+        None => Ok(parsed),
     }
+}
 
-    #[test]
-    fn test_part_two() {
-        let input = advent_of_code::read_file("examples", DAY);
-        assert_eq!(part_two(&input), None);
+/// Replaces every `{{name}}` in `template`. Unknown names are an error, so typos don't end up in the output.
+fn render(template: &str, vars: &[(&str, String)]) -> Result<String, String> {
+    let mut output = String::with_capacity(template.len());
+    let mut rest = template;
+
+    while let Some(start) = rest.find("{{") {
+        let end = rest[start..]
+            .find("}}")
+            .ok_or_else(|| "unclosed \"{{\"".to_string())?;
+        let name = rest[start + 2..start + end].trim();
+
+        let (_, value) = vars
+            .iter()
+            .find(|(var, _)| *var == name)
+            .ok_or_else(|| format!("unknown variable \"{}\"", name))?;
+
+        output.push_str(&rest[..start]);
+        output.push_str(value);
+        rest = &rest[start + end + 2..];
     }
+
+    output.push_str(rest);
+    Ok(output)
 }
-"###;
 
-fn parse_args() -> Result<u8, pico_args::Error> {
-    let mut args = pico_args::Arguments::from_env();
-    args.free_from_str()
+fn render_file(templates: &Path, name: &str, vars: &[(&str, String)]) -> Result<String, String> {
+    let path = templates.join(name);
+    let template = fs::read_to_string(&path)
+        .map_err(|e| format!("could not read template \"{}\": {}", path.display(), e))?;
+
+    render(&template, vars).map_err(|e| format!("in template \"{}\": {}", path.display(), e))
+}
+
+/// Variables available to every template.
+fn variables(args: &Args, templates: &Path) -> Result<Vec<(&'static str, String)>, String> {
+    let mut vars = vec![
+        ("day", args.day.to_string()),
+        ("padded", format!("{:02}", args.day)),
+        ("year", args.year.to_string()),
+        (
+            "title",
+            args.title
+                .clone()
+                .unwrap_or_else(|| format!("Day {}", args.day)),
+        ),
+        ("answer_one", args.answer_one.clone()),
+        ("answer_two", args.answer_two.clone()),
+    ];
+
+    let parse_input = "parsers::parse_input(input).expect(\"couldn't parse input\").1";
+
+    let (input_type, parse) = match (args.input_struct, args.parsers) {
+        (true, _) => ("Input", "Input::from_input(input)".to_string()),
+        (false, true) => ("Vec<&'a str>", parse_input.to_string()),
+        (false, false) => ("&'a str", "input".to_string()),
+    };
+    vars.push(("input_type", input_type.to_string()));
+    vars.push(("parse", parse));
+
+    vars.push((
+        "lines",
+        match args.parsers {
+            true => format!("{}.into_iter().map(str::to_string).collect()", parse_input),
+            false => "input.lines().map(str::to_string).collect()".to_string(),
+        },
+    ));
+
+    let input_struct = match args.input_struct {
+        true => render_file(templates, "input.rs.tpl", &vars)?,
+        false => String::new(),
+    };
+    let parsers = match args.parsers {
+        true => render_file(templates, "parsers.rs.tpl", &vars)?,
+        false => String::new(),
+    };
+    vars.push(("input_struct", input_struct));
+    vars.push(("parsers", parsers));
+
+    Ok(vars)
+}
+
+fn create_parent_dir(path: &str) -> Result<(), std::io::Error> {
+    match Path::new(path).parent() {
+        Some(parent) => fs::create_dir_all(parent),
+        None => Ok(()),
+    }
 }
 
 fn safe_create_file(path: &str) -> Result<File, std::io::Error> {
+    create_parent_dir(path)?;
     OpenOptions::new().write(true).create_new(true).open(path)
 }
 
 fn create_file(path: &str) -> Result<File, std::io::Error> {
-    OpenOptions::new().write(true).create(true).open(path)
+    create_parent_dir(path)?;
+    OpenOptions::new()
+        .write(true)
+        .create(true)
+        .truncate(false)
+        .open(path)
 }
 
-fn main() {
-    let day = match parse_args() {
-        Ok(day) => day,
-        Err(_) => {
-            eprintln!("Need to specify a day (as integer). example: `cargo scaffold 7`");
-            process::exit(1);
-        }
-    };
-
-    let day_padded = format!("{:02}", day);
+/// Creates `path` with `contents`, leaving an existing file untouched.
+fn write_new_file(kind: &str, path: &str, contents: &str, dry_run: bool) {
+    if Path::new(path).exists() {
+        println!("Skipped {} file \"{}\", it already exists", kind, path);
+        return;
+    }
 
-    let input_path = format!("src/inputs/{}.txt", day_padded);
-    let example_path = format!("src/examples/{}.txt", day_padded);
-    let module_path = format!("src/bin/{}.rs", day_padded);
+    if dry_run {
+        println!("Would create {} file \"{}\":", kind, path);
+        println!("{}", contents);
+        return;
+    }
 
-    let mut file = match safe_create_file(&module_path) {
+    let mut file = match safe_create_file(path) {
         Ok(file) => file,
         Err(e) => {
-            eprintln!("Failed to create module file: {}", e);
+            eprintln!("Failed to create {} file: {}", kind, e);
             process::exit(1);
         }
     };
 
-    match file.write_all(MODULE_TEMPLATE.replace("DAY", &day.to_string()).as_bytes()) {
+    match file.write_all(contents.as_bytes()) {
         Ok(_) => {
-            println!("Created module file \"{}\"", &module_path);
+            println!("Created {} file \"{}\"", kind, path);
         }
         Err(e) => {
-            eprintln!("Failed to write module contents: {}", e);
+            eprintln!("Failed to write {} contents: {}", kind, e);
             process::exit(1);
         }
     }
+}
 
-    match create_file(&input_path) {
+/// Creates an empty `path`, keeping it as is if it already exists.
+fn touch_file(kind: &str, path: &str, dry_run: bool) {
+    if Path::new(path).exists() {
+        println!("Skipped {} file \"{}\", it already exists", kind, path);
+        return;
+    }
+
+    if dry_run {
+        println!("Would create empty {} file \"{}\"", kind, path);
+        return;
+    }
+
+    match create_file(path) {
         Ok(_) => {
-            println!("Created empty input file \"{}\"", &input_path);
+            println!("Created empty {} file \"{}\"", kind, path);
         }
         Err(e) => {
-            eprintln!("Failed to create input file: {}", e);
+            eprintln!("Failed to create {} file: {}", kind, e);
             process::exit(1);
         }
     }
+}
 
-    match create_file(&example_path) {
-        Ok(_) => {
-            println!("Created empty example file \"{}\"", &example_path);
+/// Inserts `entry` as a line of the `name! { .. }` block in `source`, ordered by `key`.
+/// `key_of` extracts the key of existing lines. Returns `None` if `key` is there already.
+fn insert_entry(
+    source: &str,
+    name: &str,
+    key: u16,
+    entry: &str,
+    key_of: impl Fn(&str) -> Option<u16>,
+) -> Result<Option<String>, String> {
+    let block = format!("{}! {{", name);
+    let start = source
+        .find(&block)
+        .ok_or_else(|| format!("couldn't find the `{}!` block", name))?;
+    let end = start
+        + source[start..]
+            .find("\n}")
+            .ok_or_else(|| format!("couldn't find the end of the `{}!` block", name))?;
+
+    let mut insert_at = end + 1;
+    let mut offset = start;
+    for line in source[start..end + 1].split_inclusive('\n') {
+        match key_of(line.trim()) {
+            Some(existing) if existing == key => return Ok(None),
+            Some(existing) if existing > key => {
+                insert_at = offset;
+                break;
+            }
+            _ => {}
+        }
+        offset += line.len();
+    }
+
+    let mut updated = source.to_string();
+    updated.insert_str(insert_at, &format!("    {}\n", entry));
+    Ok(Some(updated))
+}
+
+/// Adds `day` to the `register!` block of a year's `mod.rs`, keeping the days sorted.
+fn register_day(registry: &str, day: u8) -> Result<Option<String>, String> {
+    let entry = format!("{} => day{:02}::Day{:02},", day, day, day);
+    insert_entry(registry, "register", day.into(), &entry, |line| {
+        line.split("=>").next()?.trim().parse().ok()
+    })
+}
+
+/// Adds the module of `year` to the `years!` block of `src/days/mod.rs`.
+fn register_year(registry: &str, year: u16) -> Result<Option<String>, String> {
+    let entry = format!("y{},", year);
+    insert_entry(registry, "years", year, &entry, |line| {
+        line.strip_prefix('y')?.trim_end_matches(',').parse().ok()
+    })
+}
+
+/// Appends a `### Day NN` stub to the journal section of the README, prefixed with the year
+/// unless it is `FIRST_YEAR`. Returns `None` if the day has a journal entry already.
+fn add_journal_stub(readme: &str, year: u16, day: u8) -> Result<Option<String>, String> {
+    let prefix = match year {
+        FIRST_YEAR => String::new(),
+        _ => format!("{} ", year),
+    };
+    let headings = [
+        format!("### {}Day {}", prefix, day),
+        format!("### {}Day {:02}", prefix, day),
+    ];
+    if readme
+        .lines()
+        .any(|line| headings.iter().any(|heading| line.trim_end() == heading))
+    {
+        return Ok(None);
+    }
+
+    let start = readme
+        .find("## Journal")
+        .ok_or("couldn't find the `## Journal` section")?;
+    let end = readme[start + 1..]
+        .find("\n## ")
+        .map_or(readme.len(), |end| start + 1 + end + 1);
+
+    let section = readme[start..end].trim_end();
+    let stub = format!(
+        "\n\n### {}Day {:02}\n#### Part 1: --:--:-- --> Part 2: --:--:--\n",
+        prefix, day
+    );
+
+    let mut updated = String::with_capacity(readme.len() + stub.len());
+    updated.push_str(&readme[..start]);
+    updated.push_str(section);
+    updated.push_str(&stub);
+    if end < readme.len() {
+        updated.push('\n');
+    }
+    updated.push_str(&readme[end..]);
+    Ok(Some(updated))
+}
+
+/// Rewrites `path` with `update`, reporting what happened.
+fn update_file(
+    kind: &str,
+    path: &str,
+    dry_run: bool,
+    update: impl Fn(&str) -> Result<Option<String>, String>,
+) {
+    let contents = match fs::read_to_string(path) {
+        Ok(contents) => contents,
+        Err(e) if e.kind() == ErrorKind::NotFound && dry_run => {
+            println!("Would add {} to \"{}\"", kind, path);
+            return;
+        }
+        Err(e) if e.kind() == ErrorKind::NotFound => {
+            println!("Skipped {}, \"{}\" doesn't exist", kind, path);
+            return;
         }
         Err(e) => {
-            eprintln!("Failed to create example file: {}", e);
+            eprintln!("Failed to read \"{}\": {}", path, e);
             process::exit(1);
         }
+    };
+
+    let updated = match update(&contents) {
+        Ok(Some(updated)) => updated,
+        Ok(None) => {
+            println!("Skipped {} in \"{}\", it is there already", kind, path);
+            return;
+        }
+        Err(e) => {
+            eprintln!("Failed to add {} to \"{}\": {}", kind, path, e);
+            process::exit(1);
+        }
+    };
+
+    if dry_run {
+        println!("Would add {} to \"{}\"", kind, path);
+        return;
     }
 
-    println!("---");
-    println!(
-        "🎄 Type `cargo solve {}` to run your solution.",
-        &day_padded
+    match fs::write(path, updated) {
+        Ok(_) => println!("Added {} to \"{}\"", kind, path),
+        Err(e) => {
+            eprintln!("Failed to write \"{}\": {}", path, e);
+            process::exit(1);
+        }
+    }
+}
+
+fn main() {
+    let args = match parse_args() {
+        Ok(args) => args,
+        Err(pico_args::Error::MissingArgument) => {
+            eprintln!("Need to specify a day (as integer). example: `cargo scaffold 7`");
+            process::exit(1);
+        }
+        Err(e) => {
+            eprintln!("{}\n{}", e, USAGE);
+            process::exit(1);
+        }
+    };
+
+    let templates = Path::new(TEMPLATES_DIR);
+    let rendered = variables(&args, templates).and_then(|vars| {
+        let module = render_file(templates, "module.rs.tpl", &vars)?;
+        let bin = render_file(templates, "bin.rs.tpl", &vars)?;
+        let year_module = render_file(templates, "year.rs.tpl", &vars)?;
+        Ok((module, bin, year_module))
+    });
+
+    let (module, bin, year_module) = match rendered {
+        Ok(rendered) => rendered,
+        Err(e) => {
+            eprintln!("Failed to render templates: {}", e);
+            process::exit(1);
+        }
+    };
+
+    let day_padded = format!("{:02}", args.day);
+    let bin_name = bin_name(args.year, args.day);
+
+    let input_path = format!("src/inputs/{}/{}.txt", args.year, day_padded);
+    let example_path = format!("src/examples/{}/{}.txt", args.year, day_padded);
+    let example_answers_path = format!("src/examples/{}/{}.json", args.year, day_padded);
+    let year_module_path = format!("src/days/y{}/mod.rs", args.year);
+    let module_path = format!("src/days/y{}/day{}.rs", args.year, day_padded);
+    let bin_path = format!("src/bin/{}.rs", bin_name);
+
+    write_new_file("year module", &year_module_path, &year_module, args.dry_run);
+    write_new_file("module", &module_path, &module, args.dry_run);
+    write_new_file("binary", &bin_path, &bin, args.dry_run);
+    touch_file("input", &input_path, args.dry_run);
+    touch_file("example", &example_path, args.dry_run);
+    write_new_file(
+        "example answers",
+        &example_answers_path,
+        EXAMPLE_ANSWERS_STUB,
+        args.dry_run,
+    );
+
+    update_file("`years!` entry", YEARS_PATH, args.dry_run, |registry| {
+        register_year(registry, args.year)
+    });
+    update_file(
+        "`register!` entry",
+        &year_module_path,
+        args.dry_run,
+        |registry| register_day(registry, args.day),
     );
+    update_file("journal entry", README_PATH, args.dry_run, |readme| {
+        add_journal_stub(readme, args.year, args.day)
+    });
+
+    if args.dry_run {
+        return;
+    }
+
+    println!("---");
+    println!("🎄 Type `cargo solve {}` to run your solution.", &bin_name);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args() -> Args {
+        Args {
+            day: 7,
+            year: 2022,
+            title: Some("No Space Left On Device".to_string()),
+            answer_one: "u32".to_string(),
+            answer_two: "String".to_string(),
+            parsers: true,
+            input_struct: true,
+            dry_run: true,
+        }
+    }
+
+    #[test]
+    fn test_render() {
+        let vars = [("day", "7".to_string()), ("padded", "07".to_string())];
+
+        assert_eq!(
+            render("Day{{padded}} is day {{ day }}.", &vars).unwrap(),
+            "Day07 is day 7."
+        );
+        assert!(render("{{days}}", &vars).is_err());
+        assert!(render("{{day", &vars).is_err());
+    }
+
+    #[test]
+    fn test_templates() {
+        let templates = Path::new(env!("CARGO_MANIFEST_DIR")).join(TEMPLATES_DIR);
+        let vars = variables(&args(), &templates).unwrap();
+        let module = render_file(&templates, "module.rs.tpl", &vars).unwrap();
+
+        assert!(module.starts_with("//! No Space Left On Device\n"));
+        assert!(module.contains("pub struct Day07;"));
+        assert!(module.contains("type AnswerTwo = String;"));
+        assert!(module.contains("mod parsers {"));
+        assert!(module.contains("Input::from_input(input)"));
+    }
+
+    #[test]
+    fn test_register_day() {
+        let registry = "register! {\n    1 => day01::Day01,\n    3 => day03::Day03,\n}\n";
+
+        assert_eq!(
+            register_day(registry, 2).unwrap().unwrap(),
+            "register! {\n    1 => day01::Day01,\n    2 => day02::Day02,\n    3 => day03::Day03,\n}\n"
+        );
+        assert_eq!(
+            register_day(registry, 4).unwrap().unwrap(),
+            "register! {\n    1 => day01::Day01,\n    3 => day03::Day03,\n    4 => day04::Day04,\n}\n"
+        );
+        assert_eq!(register_day(registry, 3).unwrap(), None);
+        assert!(register_day("", 3).is_err());
+    }
+
+    #[test]
+    fn test_register_year() {
+        let registry = "years! {\n    y2022,\n}\n";
+
+        assert_eq!(
+            register_year(registry, 2021).unwrap().unwrap(),
+            "years! {\n    y2021,\n    y2022,\n}\n"
+        );
+        assert_eq!(
+            register_year(registry, 2023).unwrap().unwrap(),
+            "years! {\n    y2022,\n    y2023,\n}\n"
+        );
+        assert_eq!(register_year(registry, 2022).unwrap(), None);
+    }
+
+    #[test]
+    fn test_add_journal_stub() {
+        let readme = "# AoC\n## Journal\n### Day 01\n- notes\n### Day 9\n- more";
+
+        assert_eq!(
+            add_journal_stub(readme, 2022, 10).unwrap().unwrap(),
+            "# AoC\n## Journal\n### Day 01\n- notes\n### Day 9\n- more\n\n\
+             ### Day 10\n#### Part 1: --:--:-- --> Part 2: --:--:--\n"
+        );
+        assert_eq!(add_journal_stub(readme, 2022, 1).unwrap(), None);
+        assert_eq!(add_journal_stub(readme, 2022, 9).unwrap(), None);
+
+        let readme = "## Journal\n### Day 01\n\n## License\nMIT\n";
+        assert_eq!(
+            add_journal_stub(readme, 2022, 2).unwrap().unwrap(),
+            "## Journal\n### Day 01\n\n### Day 02\n#### Part 1: --:--:-- --> Part 2: --:--:--\n\n## License\nMIT\n"
+        );
+    }
 }
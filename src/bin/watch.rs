@@ -0,0 +1,296 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use advent_of_code::{bin_name, default_year, file_path, folder_path, ANSI_BOLD, ANSI_RESET};
+use serde::Deserialize;
+use std::{
+    env, fs,
+    path::{Path, PathBuf},
+    process::{self, Command},
+    thread,
+    time::{Duration, SystemTime},
+};
+
+const DEFAULT_INTERVAL_MS: u64 = 500;
+
+const USAGE: &str = "Usage: `cargo watch-day <day> [--year <year>] [--interval <ms>] [--release]`";
+
+struct Args {
+    day: u8,
+    year: u16,
+    interval: Duration,
+    release: bool,
+}
+
+fn parse_args() -> Result<Args, pico_args::Error> {
+    let mut args = pico_args::Arguments::from_env();
+
+    let parsed = Args {
+        year: args
+            .opt_value_from_str("--year")?
+            .unwrap_or_else(default_year),
+        interval: Duration::from_millis(
+            args.opt_value_from_str("--interval")?
+                .unwrap_or(DEFAULT_INTERVAL_MS),
+        ),
+        release: args.contains("--release"),
+        day: args.free_from_str()?,
+    };
+
+    match args.finish().first() {
+        Some(arg) => Err(pico_args::Error::ArgumentParsingFailed {
+            cause: format!("unexpected argument {:?}", arg),
+        }),
+        None => Ok(parsed),
+    }
+}
+
+/// The solution, its binary, its examples and its input.
+fn watched_paths(year: u16, day: u8) -> Vec<PathBuf> {
+    let src = Path::new(env!("CARGO_MANIFEST_DIR")).join("src");
+    let example = file_path("examples", year, day);
+
+    vec![
+        src.join(format!("days/y{}/day{:02}.rs", year, day)),
+        src.join(format!("bin/{}.rs", bin_name(year, day))),
+        example.with_extension("json"),
+        folder_path("examples")
+            .join(year.to_string())
+            .join(format!("{:02}", day)),
+        example,
+        file_path("inputs", year, day),
+    ]
+}
+
+/// Modification times of `paths` and the files directly inside them, `None` for missing ones.
+fn snapshot(paths: &[PathBuf]) -> Vec<(PathBuf, Option<SystemTime>)> {
+    let modified = |path: &Path| fs::metadata(path).and_then(|meta| meta.modified()).ok();
+
+    paths
+        .iter()
+        .flat_map(|path| {
+            let mut entries: Vec<PathBuf> = match fs::read_dir(path) {
+                Ok(dir) => dir.filter_map(|entry| Some(entry.ok()?.path())).collect(),
+                Err(_) => Vec::new(),
+            };
+            entries.sort();
+            entries.insert(0, path.clone());
+            entries
+        })
+        .map(|path| {
+            let time = modified(&path);
+            (path, time)
+        })
+        .collect()
+}
+
+/// The fields of a `--format json` result that are shown here.
+#[derive(Debug, Clone, Eq, PartialEq, Deserialize)]
+struct Answer {
+    part: u8,
+    answer: Option<String>,
+    status: String,
+}
+
+impl Answer {
+    fn describe(&self) -> String {
+        match &self.answer {
+            Some(answer) if self.status == "solved" => answer.clone(),
+            Some(answer) => format!("{} ({})", answer, self.status),
+            None => self.status.clone(),
+        }
+    }
+}
+
+/// One line per part, comparing the answers with the ones of the previous run.
+fn diff_answers(previous: &[Answer], current: &[Answer]) -> Vec<String> {
+    current
+        .iter()
+        .map(|answer| {
+            let before = previous.iter().find(|before| before.part == answer.part);
+            match before {
+                Some(before) if before == answer => {
+                    format!("Part {}: {} (unchanged)", answer.part, answer.describe())
+                }
+                Some(before) => format!(
+                    "Part {}: {} -> {}",
+                    answer.part,
+                    before.describe(),
+                    answer.describe()
+                ),
+                None => format!("Part {}: {}", answer.part, answer.describe()),
+            }
+        })
+        .collect()
+}
+
+fn cargo(args: &Args, subcommand: &str) -> Command {
+    let mut command = Command::new(env::var("CARGO").unwrap_or_else(|_| "cargo".to_string()));
+    command
+        .current_dir(env!("CARGO_MANIFEST_DIR"))
+        .args([subcommand, "--quiet"]);
+    if args.release {
+        command.arg("--release");
+    }
+    command
+}
+
+/// Runs the unit tests of the day's module, which check its default example, and the sidecar
+/// check of every example with expected answers, including the named ones in `NN/`.
+fn run_tests(args: &Args) -> bool {
+    let filter = format!("days::y{}::day{:02}::", args.year, args.day);
+    let mut module = cargo(args, "test");
+    module.args(["--lib", &filter]);
+
+    let mut sidecars = cargo(args, "test");
+    sidecars
+        .args(["--lib", "examples::tests::test_examples", "--", "--exact"])
+        .env("AOC_EXAMPLES_DAY", bin_name(args.year, args.day));
+
+    // Both run even if the first fails, so the output shows every failing example.
+    let module_passed = run_test_command(&mut module);
+    let sidecars_passed = run_test_command(&mut sidecars);
+    module_passed && sidecars_passed
+}
+
+fn run_test_command(command: &mut Command) -> bool {
+    match command.status() {
+        Ok(status) => status.success(),
+        Err(e) => {
+            eprintln!("Failed to run the example tests: {}", e);
+            false
+        }
+    }
+}
+
+/// Runs the day on its real input. `None` if it failed to build or run.
+fn run_input(args: &Args) -> Option<Vec<Answer>> {
+    let bin = bin_name(args.year, args.day);
+    let mut command = cargo(args, "run");
+    command.args(["--bin", &bin, "--", "--format", "json"]);
+
+    let output = match command.output() {
+        Ok(output) => output,
+        Err(e) => {
+            eprintln!("Failed to run `cargo solve {}`: {}", bin, e);
+            return None;
+        }
+    };
+
+    if !output.status.success() && output.stdout.is_empty() {
+        eprint!("{}", String::from_utf8_lossy(&output.stderr));
+        return None;
+    }
+
+    match serde_json::from_slice(&output.stdout) {
+        Ok(answers) => Some(answers),
+        Err(e) => {
+            eprintln!("Failed to read the answers of `cargo solve {}`: {}", bin, e);
+            None
+        }
+    }
+}
+
+fn main() {
+    let args = match parse_args() {
+        Ok(args) => args,
+        Err(e) => {
+            eprintln!("Failed to process arguments: {}", e);
+            eprintln!("{}", USAGE);
+            process::exit(1);
+        }
+    };
+
+    let mut seen = None;
+    let mut previous: Vec<Answer> = Vec::new();
+
+    println!(
+        "Watching {} day {}, press Ctrl+C to stop.",
+        args.year, args.day
+    );
+    for path in watched_paths(args.year, args.day) {
+        println!("- {}", path.display());
+    }
+
+    loop {
+        // Recomputed, since the input may move from the legacy flat layout into its year folder.
+        let current = snapshot(&watched_paths(args.year, args.day));
+        if seen.as_ref() == Some(&current) {
+            thread::sleep(args.interval);
+            continue;
+        }
+        seen = Some(current);
+
+        println!("---------------");
+        println!(
+            "{}| {} Day {:02} |{}",
+            ANSI_BOLD, args.year, args.day, ANSI_RESET
+        );
+        println!("---------------");
+
+        match run_tests(&args) {
+            true => println!("Examples: ok"),
+            false => println!("Examples: failed"),
+        }
+
+        if let Some(answers) = run_input(&args) {
+            for line in diff_answers(&previous, &answers) {
+                println!("{}", line);
+            }
+            previous = answers;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn answer(part: u8, answer: Option<&str>, status: &str) -> Answer {
+        Answer {
+            part,
+            answer: answer.map(str::to_string),
+            status: status.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_diff_answers() {
+        let previous = vec![
+            answer(1, Some("24000"), "solved"),
+            answer(2, None, "unsolved"),
+        ];
+        let current = vec![
+            answer(1, Some("24000"), "solved"),
+            answer(2, Some("45000"), "wrong"),
+        ];
+
+        assert_eq!(
+            diff_answers(&previous, &current),
+            [
+                "Part 1: 24000 (unchanged)",
+                "Part 2: unsolved -> 45000 (wrong)"
+            ]
+        );
+        assert_eq!(diff_answers(&[], &current[..1]), ["Part 1: 24000"]);
+    }
+
+    #[test]
+    fn test_snapshot() {
+        let dir = env::temp_dir().join(format!("aoc-watch-{}", process::id()));
+        let file = dir.join("01.txt");
+        fs::create_dir_all(&dir).unwrap();
+
+        let missing = snapshot(std::slice::from_ref(&file));
+        assert_eq!(missing, [(file.clone(), None)]);
+
+        fs::write(&file, "1000").unwrap();
+        let snapshot = snapshot(std::slice::from_ref(&dir));
+        assert_eq!(snapshot.len(), 2);
+        assert_eq!(snapshot[1].0, file);
+        assert!(snapshot[1].1.is_some());
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,47 @@
+/*
+ * Every solved day is registered here, grouped by year.
+ * Add a `N => dayNN::DayNN` line to `register!` in `./yYYYY/mod.rs` after creating `./yYYYY/dayNN.rs`,
+ * and a `yYYYY` line to `years!` when starting a new year.
+ */
+use crate::runner::Day;
+
+/// Declares the day modules of a year and lists them in `DAYS`. Expects a `YEAR` constant.
+macro_rules! register {
+    ($($day:literal => $module:ident::$solution:ident),* $(,)?) => {
+        $(pub mod $module;)*
+
+        pub const DAYS: &[$crate::runner::Day] = &[$(
+            $crate::runner::Day {
+                year: YEAR,
+                day: $day,
+                params: <$module::$solution as $crate::Solution>::PARAMS,
+                solve: $crate::runner::run_solution::<$module::$solution>,
+            },
+        )*];
+    };
+}
+
+macro_rules! years {
+    ($($module:ident),* $(,)?) => {
+        $(pub mod $module;)*
+
+        /// Registered days of every year, oldest first.
+        pub const YEARS: &[&[Day]] = &[$($module::DAYS),*];
+    };
+}
+
+years! {
+    y2022,
+}
+
+pub fn all() -> impl Iterator<Item = &'static Day> {
+    YEARS.iter().flat_map(|days| days.iter())
+}
+
+pub fn years() -> impl Iterator<Item = u16> {
+    YEARS.iter().filter_map(|days| Some(days.first()?.year))
+}
+
+pub fn get(year: u16, day: u8) -> Option<&'static Day> {
+    all().find(|entry| entry.year == year && entry.day == day)
+}
@@ -0,0 +1,54 @@
+use itertools::Itertools;
+use crate::Solution;
+
+
+pub fn sum_elves(input: &str) -> impl Iterator<Item = u32> + '_ {
+    input.lines()
+        .batching(|lines| {
+            lines.take_while(|&line| !line.is_empty())
+                .filter_map(|str| str.parse::<u32>().ok())
+                .sum1::<u32>()
+        })
+}
+
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    type Input<'a> = &'a str;
+    type AnswerOne = u32;
+    type AnswerTwo = u32;
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        input
+    }
+
+    fn part_one(input: &Self::Input<'_>) -> Option<u32> {
+        sum_elves(input).max()
+    }
+
+    fn part_two(input: &Self::Input<'_>) -> Option<u32> {
+        sum_elves(input)
+            .sorted_unstable()
+            .rev()
+            .take(3)
+            .sum1()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let input = crate::read_file("examples", 2022, 1).unwrap();
+        assert_eq!(Day01::solve_part_one(&input), Some(24000));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = crate::read_file("examples", 2022, 1).unwrap();
+        assert_eq!(Day01::solve_part_two(&input), Some(45000));
+    }
+}
@@ -0,0 +1,125 @@
+use itertools::Itertools;
+use crate::Solution;
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+enum Rps {
+    Rock,
+    Paper,
+    Scissors
+}
+
+impl Rps {
+    fn from(s: &str) -> Option<Self> {
+        match s {
+            "A" | "X" => Some(Self::Rock),
+            "B" | "Y" => Some(Self::Paper),
+            "C" | "Z" => Some(Self::Scissors),
+            _ => None
+        }
+    }
+
+    #[allow(clippy::identity_op)]
+    fn play_against(self, other: Self) -> u32 {
+        match self {
+            Rps::Rock => {
+                let x = 1;
+                match other {
+                    Rps::Rock => x + 3,
+                    Rps::Paper => x + 0,
+                    Rps::Scissors => x + 6,
+                }
+            }
+            Rps::Paper => {
+                let x = 2;
+                match other {
+                    Rps::Rock => x + 6,
+                    Rps::Paper => x + 3,
+                    Rps::Scissors => x + 0,
+                }
+            }
+            Rps::Scissors => {
+                let x = 3;
+                match other {
+                    Rps::Rock => x + 0,
+                    Rps::Paper => x + 6,
+                    Rps::Scissors => x + 3,
+                }
+            }
+        }
+    }
+
+    #[allow(clippy::identity_op)]
+    fn play_against2(self, other: Self) -> u32 {
+        match self {
+            Rps::Rock => {
+                let x = 0;
+                match other {
+                    Rps::Rock => x + 3,
+                    Rps::Paper => x + 1,
+                    Rps::Scissors => x + 2,
+                }
+            }
+            Rps::Paper => {
+                let x = 3;
+                match other {
+                    Rps::Rock => x + 1,
+                    Rps::Paper => x + 2,
+                    Rps::Scissors => x + 3,
+                }
+            }
+            Rps::Scissors => {
+                let x = 6;
+                match other {
+                    Rps::Rock => x + 2,
+                    Rps::Paper => x + 3,
+                    Rps::Scissors => x + 1,
+                }
+            }
+        }
+    }
+}
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    type Input<'a> = &'a str;
+    type AnswerOne = u32;
+    type AnswerTwo = u32;
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        input
+    }
+
+    fn part_one(input: &Self::Input<'_>) -> Option<u32> {
+        input.lines()
+            .filter_map(|line| line.split(' ').collect_tuple::<(_, _)>())
+            .filter_map(|(enemy, me)| Rps::from(enemy).zip(Rps::from(me)))
+            .map(|(enemy, me)| me.play_against(enemy))
+            .sum1()
+    }
+
+    fn part_two(input: &Self::Input<'_>) -> Option<u32> {
+        input.lines()
+            .filter_map(|line| line.split(' ').collect_tuple::<(_, _)>())
+            .filter_map(|(enemy, me)| Rps::from(enemy).zip(Rps::from(me)))
+            .map(|(enemy, me)| me.play_against2(enemy))
+            .sum1()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let input = crate::read_file("examples", 2022, 2).unwrap();
+        assert_eq!(Day02::solve_part_one(&input), Some(15));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = crate::read_file("examples", 2022, 2).unwrap();
+        assert_eq!(Day02::solve_part_two(&input), Some(12));
+    }
+}
@@ -0,0 +1,64 @@
+use itertools::Itertools;
+use crate::Solution;
+
+fn inside(a: &[u32; 2], b: &[u32; 2]) -> bool {
+    (a[0] <= b[0] && a[1] >= b[1]) || (b[0] <= a[0] && b[1] >= a[1])
+}
+
+fn overlaps(a: &[u32; 2], b: &[u32; 2]) -> bool {
+    a[0] <= b[1] && a[1] >= b[0]
+}
+
+fn solve(input: &str, predicate: impl Fn(&[u32; 2], &[u32; 2]) -> bool) -> Option<u32> {
+    let res = input.lines()
+        .flat_map(|line| line.split(','))
+        .flat_map(|half| half.split('-'))
+        .filter_map(|quarter| quarter.parse::<u32>().ok())
+        .batching(|quarters| {
+            Some([
+                [quarters.next()?, quarters.next()?],
+                [quarters.next()?, quarters.next()?],
+            ])
+        })
+        .filter(|[a, b]| predicate(a, b))
+        .count();
+
+    Some(res as u32)
+}
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    type Input<'a> = &'a str;
+    type AnswerOne = u32;
+    type AnswerTwo = u32;
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        input
+    }
+
+    fn part_one(input: &Self::Input<'_>) -> Option<u32> {
+        solve(input, inside)
+    }
+
+    fn part_two(input: &Self::Input<'_>) -> Option<u32> {
+        solve(input, overlaps)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let input = crate::read_file("examples", 2022, 4).unwrap();
+        assert_eq!(Day04::solve_part_one(&input), Some(2));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = crate::read_file("examples", 2022, 4).unwrap();
+        assert_eq!(Day04::solve_part_two(&input), Some(4));
+    }
+}
@@ -0,0 +1,49 @@
+use std::collections::HashSet;
+use crate::Solution;
+
+fn solve(input: &str, len: usize) -> Option<u32> {
+    input.as_bytes().windows(len)
+        .enumerate()
+        .find(|(_, bytes)| {
+            let set: HashSet<u8> = HashSet::from_iter(bytes.iter().copied());
+            set.len() == len
+        })
+        .map(|(i, _)| (i + len) as u32)
+}
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    type Input<'a> = &'a str;
+    type AnswerOne = u32;
+    type AnswerTwo = u32;
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        input
+    }
+
+    fn part_one(input: &Self::Input<'_>) -> Option<u32> {
+        solve(input, 4)
+    }
+
+    fn part_two(input: &Self::Input<'_>) -> Option<u32> {
+        solve(input, 14)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let input = crate::read_file("examples", 2022, 6).unwrap();
+        assert_eq!(Day06::solve_part_one(&input), Some(7));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = crate::read_file("examples", 2022, 6).unwrap();
+        assert_eq!(Day06::solve_part_two(&input), Some(19));
+    }
+}
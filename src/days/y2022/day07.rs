@@ -0,0 +1,182 @@
+use std::cell::RefCell;
+use std::rc::{Rc, Weak};
+use itertools::Itertools;
+use crate::Solution;
+
+#[allow(dead_code)]
+pub struct Dir {
+    name: String,
+    parent: Weak<Dir>,
+    files: Vec<File>,
+    dirs: RefCell<Vec<Rc<Dir>>>,
+}
+
+#[allow(dead_code)]
+struct File {
+    name: String,
+    size: u32,
+}
+
+impl Dir {
+    fn new<'a, I>(mut input: &mut I, name: &str, parent: Weak<Dir>) -> Rc<Self>
+    where I: Iterator<Item=&'a str>
+    {
+        let mut dir = Dir {
+            name: name.to_string(),
+            parent,
+            files: vec![],
+            dirs: RefCell::new(vec![]),
+        };
+
+        let mut last = None;
+
+        for line in &mut input {
+            let cmd = parse_command(line);
+            match cmd {
+                Some(Command::LS) => continue,
+                Some(Command::CD(_)) => {
+                    last = Some(line);
+                    break
+                }
+                None => {
+                    if line.starts_with("dir") {
+                        // We're gonna add these when we CD into them
+                        continue
+                    }
+
+                    let mut iter = line.split(' ');
+                    let size = iter.next().unwrap().parse::<u32>().unwrap();
+                    let name = iter.next().unwrap();
+                    dir.files.push(File {
+                        name: name.to_string(),
+                        size,
+                    })
+                }
+            }
+        }
+
+        let dir = Rc::new(dir);
+
+        loop {
+            let line = last.take().or_else(|| input.next());
+            let line = match line {
+                None => break,
+                Some(v) => v,
+            };
+
+            let cmd = parse_command(line);
+            match cmd {
+                None => panic!("unexpected non-command"),
+                Some(Command::LS) => panic!("unexpected ls command"),
+                Some(Command::CD(next_name)) => {
+                    if next_name == ".." {
+                        break
+                    }
+
+                    let next_dir = Dir::new(&mut *input, next_name, Rc::downgrade(&dir));
+                    dir.dirs.borrow_mut().push(next_dir);
+                }
+            }
+        }
+
+        dir
+    }
+
+    fn size(&self) -> u32 {
+        let files: u32 = self.files.iter().map(|f| f.size()).sum();
+        let dirs: u32 = self.dirs.borrow().iter()
+            .map(|d| d.size())
+            .sum();
+        files + dirs
+    }
+
+    fn walk_dirs(start: Rc<Dir>) -> impl Iterator<Item=Rc<Dir>> {
+        let mut vec = vec![start];
+
+        let mut i = 0;
+        while i < vec.len() {
+            let mut dirs = vec[i].dirs.borrow().clone();
+            vec.append(&mut dirs);
+
+            i += 1;
+        }
+
+        vec.into_iter()
+    }
+}
+
+impl File {
+    fn size(&self) -> u32 {
+        self.size
+    }
+}
+
+fn parse_command(cmd: &str) -> Option<Command<'_>> {
+    if !cmd.starts_with('$') {
+        return None
+    }
+
+    let mut iter = cmd.split(' ').skip(1);
+    match iter.next()? {
+        "cd" => Some(Command::CD(iter.next()?)),
+        "ls" => Some(Command::LS),
+        _ => panic!("unrecognised command"),
+    }
+}
+
+
+
+enum Command<'a> {
+    LS,
+    CD(&'a str),
+}
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    type Input<'a> = Rc<Dir>;
+    type AnswerOne = u32;
+    type AnswerTwo = u32;
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        let mut lines = input.lines().skip(1);
+        Dir::new(&mut lines, "/", Weak::new())
+    }
+
+    fn part_one(root: &Self::Input<'_>) -> Option<u32> {
+        let sum = Dir::walk_dirs(root.clone())
+            .map(|dir| dir.size())
+            .filter(|size| *size <= 100000)
+            .sum();
+
+        Some(sum)
+    }
+
+    fn part_two(root: &Self::Input<'_>) -> Option<u32> {
+        // Update size - Empty space
+        let size_needed = 30000000 - (70000000 - root.size());
+
+        Dir::walk_dirs(root.clone())
+            .map(|dir| dir.size())
+            .filter(|size| *size >= size_needed)
+            .sorted()
+            .next()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let input = crate::read_file("examples", 2022, 7).unwrap();
+        assert_eq!(Day07::solve_part_one(&input), Some(95437));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = crate::read_file("examples", 2022, 7).unwrap();
+        assert_eq!(Day07::solve_part_two(&input), Some(24933642));
+    }
+}
@@ -0,0 +1,211 @@
+use lazy_static::lazy_static;
+use regex::Regex;
+use anyhow::{anyhow, Context, Result};
+use derivative::Derivative;
+use itertools::Itertools;
+use std::rc::Rc;
+use crate::{Params, Solution};
+
+#[derive(Derivative, Clone)]
+#[derivative(Debug)]
+pub struct Monkey {
+    inspection_count: u32,
+
+    items: Vec<Item>,
+    #[derivative(Debug="ignore")]
+    operation: Rc<dyn Fn(Item) -> Item>,
+
+    test_divisor: u32,
+    target_true: MonkeyIndex,
+    target_false: MonkeyIndex,
+}
+
+lazy_static! {
+    static ref ITEMS_RE: Regex = Regex::new(r"\d+").unwrap();
+    static ref OP_RE: Regex = Regex::new(r"= ?(.+)").unwrap();
+    static ref TEST_RE: Regex = Regex::new(r"\d+").unwrap();
+    static ref TRUE_RE: Regex = Regex::new(r"\d+").unwrap();
+    static ref FALSE_RE: Regex = Regex::new(r"\d+").unwrap();
+}
+
+pub struct Troop {
+    monkeys: Vec<Monkey>,
+    rounds_one: u32,
+    rounds_two: u32,
+    relief: Item,
+}
+
+impl Monkey {
+    fn throw(&mut self, relief: Item) -> Vec<(Item, MonkeyIndex)> {
+        self.inspection_count += self.items.len() as u32;
+
+        let operation = &*self.operation;
+        self.items.drain(..)
+            .map(|item| operation(item) as Item)
+            .map(|item| item / relief)
+            .map(|item| {
+                let target = if item % self.test_divisor as Item == 0 {
+                    self.target_true
+                } else {
+                    self.target_false
+                };
+                (item, target)
+            })
+            .collect()
+    }
+
+    fn throw_unchained(&mut self, common_multiple: u64) -> Vec<(Item, MonkeyIndex)> {
+        self.inspection_count += self.items.len() as u32;
+
+        let operation = &*self.operation;
+        self.items.drain(..)
+            .map(|item| operation(item) as Item)
+            .map(|mut item| {
+                item %= common_multiple;
+                let target = if item % self.test_divisor as Item == 0 {
+                    self.target_true
+                } else {
+                    self.target_false
+                };
+                (item, target)
+            })
+            .collect()
+    }
+
+    fn catch(&mut self, item: Item) {
+        self.items.push(item)
+    }
+
+    fn from_iter<'a, I>(lines: &mut I) -> Option<Result<Self>>
+    where I: Iterator<Item=&'a str>
+    {
+        let _monkey = lines.find(|&line| !line.is_empty())?;
+        let items_str = lines.next()?;
+        let op_str = lines.next()?;
+        let test_str = lines.next()?;
+        let true_str = lines.next()?;
+        let false_str = lines.next()?;
+
+        let res: Result<Self> = (|| {
+            let items: Vec<_> = ITEMS_RE.find_iter(items_str)
+                .map(|num| num.as_str().parse::<Item>().map_err(|e| anyhow!(e)))
+                .collect::<Result<_>>()?;
+
+            let operation = &OP_RE.captures(op_str).context("couldn't match operation")?[1];
+            let operation = operation.parse::<meval::Expr>()?.bind("old")?;
+            let operation: Rc<dyn Fn(Item) -> Item> = Rc::new(move |old| operation(old as f64).trunc() as Item);
+
+            let test_divisor: u32 = TEST_RE.find(test_str).context("couldn't match divisor")?.as_str().parse()?;
+            let target_true: MonkeyIndex = TRUE_RE.find(true_str).context("couldn't match true target")?.as_str().parse()?;
+            let target_false: MonkeyIndex = FALSE_RE.find(false_str).context("couldn't match false target")?.as_str().parse()?;
+
+            Ok(Self {
+                inspection_count: 0,
+                items,
+                operation,
+                test_divisor,
+                target_true,
+                target_false,
+            })
+        })();
+
+        Some(res)
+    }
+}
+
+type Item = u64;
+type MonkeyIndex = usize;
+
+fn get_monkeys(input: &str) -> Vec<Monkey> {
+    input.lines()
+        .batching(Monkey::from_iter)
+        .collect::<Result<_>>()
+        .expect("couldn't parse a monkey")
+}
+
+fn get_answer(mut monkeys: Vec<Monkey>) -> Option<u64> {
+    monkeys.sort_unstable_by_key(|monkey| monkey.inspection_count);
+    monkeys.into_iter()
+        .rev()
+        .take(2)
+        .map(|monkey| monkey.inspection_count as u64)
+        .product1()
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    type Input<'a> = Troop;
+    type AnswerOne = u64;
+    type AnswerTwo = u64;
+
+    const PARAMS: &'static [(&'static str, &'static str)] = &[
+        ("rounds_one", "20"),
+        ("rounds_two", "10000"),
+        ("relief", "3"),
+    ];
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        Self::parse_with(input, &Self::params())
+    }
+
+    fn parse_with<'a>(input: &'a str, params: &Params) -> Self::Input<'a> {
+        Troop {
+            monkeys: get_monkeys(input),
+            rounds_one: params.get("rounds_one"),
+            rounds_two: params.get("rounds_two"),
+            relief: params.get("relief"),
+        }
+    }
+
+    fn part_one(troop: &Self::Input<'_>) -> Option<u64> {
+        let mut monkeys = troop.monkeys.clone();
+
+        for _round in 1..=troop.rounds_one {
+            for i in 0..monkeys.len() {
+                let res = monkeys[i].throw(troop.relief);
+                for (item, j) in res {
+                    monkeys[j].catch(item)
+                }
+            }
+        }
+
+        get_answer(monkeys)
+    }
+
+    fn part_two(troop: &Self::Input<'_>) -> Option<u64> {
+        let mut monkeys = troop.monkeys.clone();
+
+        let common_multiple = monkeys.iter()
+            .map(|monkey| monkey.test_divisor as u64)
+            .product();
+
+        for _round in 1..=troop.rounds_two {
+            for i in 0..monkeys.len() {
+                let res = monkeys[i].throw_unchained(common_multiple);
+                for (item, j) in res {
+                    monkeys[j].catch(item)
+                }
+            }
+        }
+
+        get_answer(monkeys)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let input = crate::read_file("examples", 2022, 11).unwrap();
+        assert_eq!(Day11::solve_part_one(&input), Some(10605));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = crate::read_file("examples", 2022, 11).unwrap();
+        assert_eq!(Day11::solve_part_two(&input), Some(2713310158));
+    }
+}
@@ -0,0 +1,140 @@
+use glam::UVec2;
+use crate::helpers::search::{self, Graph, Search};
+use crate::helpers::Grid;
+use crate::{Params, Solution, ANSI_GREEN};
+
+pub struct Board {
+    heights: Grid<u8>,
+    start: UVec2,
+    end: UVec2,
+}
+
+impl Board {
+    fn from_input(input: &str, start_marker: u8, end_marker: u8) -> Self {
+        let mut start = None;
+        let mut end = None;
+
+        let heights = Grid::parse(input, |pos, c| match c as u8 {
+            byte if byte == start_marker => {
+                start = Some(pos);
+                byte_to_height(b'a')
+            }
+            byte if byte == end_marker => {
+                end = Some(pos);
+                byte_to_height(b'z')
+            }
+            byte @ b'a'..=b'z' => byte_to_height(byte),
+            _ => panic!("unsupported char {}", c)
+        });
+
+        let start = start.expect("Didn't find a start cell");
+        let end = end.expect("Didn't find an end cell");
+
+        Self {
+            heights,
+            start,
+            end,
+        }
+    }
+
+    /// Searches backwards from the end, so part two can stop at the first lowest cell.
+    fn search(&self, part2: bool) -> Search<UVec2, u32> {
+        if part2 {
+            search::dijkstra(self, [self.end], |pos| self.heights[pos] == 0)
+        } else {
+            search::astar(self, [self.end], |pos| pos == self.start)
+        }
+    }
+
+    fn step_allowed(&self, from: UVec2, to: UVec2) -> bool {
+        let from = self.heights[from];
+        let to = self.heights[to];
+
+        from + 1 >= to
+    }
+
+    #[allow(dead_code)]
+    fn print_visited(&self, search: &Search<UVec2, u32>) {
+        let heights = self.heights.map(|_, height| format!("{:>3}", height));
+        print!("{}", heights.to_string_highlighted(ANSI_GREEN, |pos| search.is_visited(pos)));
+    }
+}
+
+/// Steps go from the end back towards the start, so a step is allowed when its reverse is.
+impl Graph for Board {
+    type Node = UVec2;
+    type Cost = u32;
+
+    fn neighbours(&self, pos: UVec2) -> impl Iterator<Item=(UVec2, u32)> {
+        self.heights.neighbours4(pos)
+            .filter(move |&next_pos| self.step_allowed(next_pos, pos))
+            .map(|next_pos| (next_pos, 1))
+    }
+
+    fn heuristic(&self, pos: UVec2) -> u32 {
+        let offset = (pos.as_ivec2() - self.start.as_ivec2()).abs();
+        (offset.x + offset.y) as u32
+    }
+}
+
+
+fn byte_to_height(byte: u8) -> u8 {
+    byte - b'a'
+}
+
+
+
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    type Input<'a> = Board;
+    type AnswerOne = u32;
+    type AnswerTwo = u32;
+
+    const PARAMS: &'static [(&'static str, &'static str)] = &[
+        ("start", "S"),
+        ("end", "E"),
+    ];
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        Self::parse_with(input, &Self::params())
+    }
+
+    fn parse_with<'a>(input: &'a str, params: &Params) -> Self::Input<'a> {
+        let start = params.get::<char>("start");
+        let end = params.get::<char>("end");
+        assert!(start.is_ascii() && end.is_ascii(), "start and end markers must be ascii");
+
+        Board::from_input(input, start as u8, end as u8)
+    }
+
+    fn part_one(board: &Self::Input<'_>) -> Option<u32> {
+        let search = board.search(false);
+        // board.print_visited(&search);
+        search.distance()
+    }
+
+    fn part_two(board: &Self::Input<'_>) -> Option<u32> {
+        let search = board.search(true);
+        // board.print_visited(&search);
+        search.distance()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let input = crate::read_file("examples", 2022, 12).unwrap();
+        assert_eq!(Day12::solve_part_one(&input), Some(31));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = crate::read_file("examples", 2022, 12).unwrap();
+        assert_eq!(Day12::solve_part_two(&input), Some(29));
+    }
+}
@@ -0,0 +1,129 @@
+use std::cmp::Ordering;
+use itertools::{EitherOrBoth, Itertools};
+use serde::{Serialize, Deserialize};
+use crate::Solution;
+
+
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(untagged)]
+enum Item {
+    List(Vec<Item>),
+    Int(i32),
+}
+
+impl Item {
+    fn from_str(string: &str) -> Option<Self> {
+        serde_json::from_str(string).ok()
+    }
+
+    fn from_iter<'a, I>(lines: &mut I) -> Option<Self>
+    where I: Iterator<Item=&'a str>
+    {
+        let line = lines.find(|line| !line.is_empty())?;
+
+        Self::from_str(line)
+    }
+}
+
+
+impl PartialOrd<Self> for Item {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Item {
+    fn cmp(&self, right: &Self) -> Ordering {
+        let left = self;
+
+        match (left, right) {
+            (Self::Int(l), Self::Int(r)) => {
+                l.cmp(r)
+            }
+            (Self::List(l), Self::List(r)) => {
+                l.iter().zip_longest(r.iter())
+                    .map(|lr| {
+                        match lr {
+                            EitherOrBoth::Both(l, r) => l.cmp(r),
+                            EitherOrBoth::Left(_) => Ordering::Greater,
+                            EitherOrBoth::Right(_) => Ordering::Less,
+                        }
+                    })
+                    .find(|res| res != &Ordering::Equal)
+                    .unwrap_or(Ordering::Equal)
+            }
+            (Self::Int(l), Self::List(_)) => {
+                let left = Self::List(vec![Self::Int(*l)]);
+                left.cmp(right)
+            }
+            (Self::List(_), Self::Int(r)) => {
+                let right = &Self::List(vec![Self::Int(*r)]);
+                left.cmp(right)
+            }
+        }
+    }
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    type Input<'a> = &'a str;
+    type AnswerOne = u32;
+    type AnswerTwo = u32;
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        input
+    }
+
+    fn part_one(input: &Self::Input<'_>) -> Option<u32> {
+        input.lines()
+            .batching(|lines| {
+                Some((
+                    Item::from_iter(lines)?,
+                    Item::from_iter(lines)?,
+                ))
+            })
+            .enumerate()
+            .filter_map(|(i, (left, right))| {
+                let res = left < right;
+                res.then_some(i as u32 + 1)
+            })
+            .sum1()
+    }
+
+    fn part_two(input: &Self::Input<'_>) -> Option<u32> {
+        let mut items: Vec<_> = input.lines()
+            .batching(Item::from_iter)
+            .collect();
+
+        let divider_a = Item::from_str("[[2]]").unwrap();
+        let divider_b = Item::from_str("[[6]]").unwrap();
+
+        items.push(divider_a.clone());
+        items.push(divider_b.clone());
+
+        items.sort_unstable();
+
+        items.iter()
+            .positions(|item| item == &divider_a || item == &divider_b)
+            .map(|i| i as u32 + 1)
+            .product1()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let input = crate::read_file("examples", 2022, 13).unwrap();
+        assert_eq!(Day13::solve_part_one(&input), Some(13));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = crate::read_file("examples", 2022, 13).unwrap();
+        assert_eq!(Day13::solve_part_two(&input), Some(140));
+    }
+}
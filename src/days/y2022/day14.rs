@@ -0,0 +1,169 @@
+use std::iter;
+use glam::IVec2;
+use crate::helpers::SparseGrid;
+use crate::Solution;
+
+#[derive(Debug, Copy, Clone)]
+enum Cell {
+    Wall,
+    Sand,
+}
+
+#[derive(Clone)]
+pub struct Sandbox {
+    cells: SparseGrid<Cell>,
+    floor: i32,
+    void_floor: bool,
+}
+
+impl Sandbox {
+    fn from_input(input: &str) -> Self {
+        let (_, res) = parsers::parse_input(input).expect("couldn't parse input");
+
+        let cells: SparseGrid<Cell> = res.iter()
+            .flat_map(|wall| wall.windows(2))
+            .flat_map(|pair| iter_line(pair[0], pair[1]))
+            .map(|point| (point, Cell::Wall))
+            .collect();
+
+        let floor = cells.bounds().map_or(i32::MIN, |bounds| bounds.max.y);
+
+        Self {
+            cells,
+            floor,
+            void_floor: true,
+        }
+    }
+
+    fn contains(&self, pos: IVec2) -> bool {
+        self.cells.contains(pos)
+            || (!self.void_floor && pos.y >= self.floor)
+    }
+
+    fn with_fake_floor(mut self) -> Self {
+        self.floor += 2;
+        self.void_floor = false;
+        self
+    }
+
+    const SAND_OFFSETS: [IVec2; 3] = [
+        IVec2::new(0, 1),
+        IVec2::new(-1, 1),
+        IVec2::new(1, 1),
+    ];
+
+    fn drop_sand(&mut self, mut pos: IVec2) -> Option<IVec2> {
+        while pos.y < self.floor {
+            let next_offset = Self::SAND_OFFSETS.into_iter()
+                .find(|offset| !self.contains(pos + *offset));
+
+            if let Some(offset) = next_offset {
+                pos += offset
+            } else {
+                self.cells.insert(pos, Cell::Sand);
+                return Some(pos);
+            }
+        }
+
+        // Sand fell past the last floor
+        None
+    }
+}
+
+fn iter_line(from: IVec2, to: IVec2) -> impl Iterator<Item=IVec2> {
+    let range_x = if from.x <= to.x {
+        from.x..=to.x
+    } else {
+        to.x..=from.x
+    };
+
+    let range_y = if from.y <= to.y {
+        from.y..=to.y
+    } else {
+        to.y..=from.y
+    };
+
+    let iter_x = range_x.map(move |x| IVec2::new(x, to.y));
+    let iter_y = range_y.map(move |y| IVec2::new(to.x, y));
+
+    iter_x.chain(iter_y)
+}
+
+mod parsers {
+    use glam::IVec2;
+    use nom::bytes::complete::tag;
+    use nom::character::complete::{char, i32, line_ending};
+    use nom::multi::separated_list1;
+    use nom::sequence::separated_pair;
+
+    pub fn parse_input(input: &str) -> nom::IResult<&str, Vec<Vec<IVec2>>> {
+        separated_list1(line_ending, parse_line)(input)
+    }
+
+    fn parse_line(input: &str) -> nom::IResult<&str, Vec<IVec2>> {
+        separated_list1(tag(" -> "), parse_ivec)(input)
+    }
+
+    fn parse_ivec(input: &str) -> nom::IResult<&str, IVec2> {
+        let (remainder, (x, y)) = separated_pair(i32, char(','), i32)(input)?;
+        let ivec = IVec2::new(x, y);
+        Ok((remainder, ivec))
+    }
+}
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    type Input<'a> = Sandbox;
+    type AnswerOne = u32;
+    type AnswerTwo = u32;
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        Sandbox::from_input(input)
+    }
+
+    fn part_one(sandbox: &Self::Input<'_>) -> Option<u32> {
+        let mut sandbox = sandbox.clone();
+
+        let count = iter::repeat_with(|| sandbox.drop_sand(IVec2::new(500, 0)))
+            .take_while(|settled| settled.is_some())
+            .count();
+
+        Some(count as u32)
+    }
+
+    fn part_two(sandbox: &Self::Input<'_>) -> Option<u32> {
+        let mut sandbox = sandbox.clone()
+            .with_fake_floor();
+
+        let sand_spawn = IVec2::new(500, 0);
+        let count = iter::repeat_with(|| sandbox.drop_sand(sand_spawn))
+            .take_while(|settled| {
+                if let Some(pos) = settled {
+                    *pos != sand_spawn
+                } else {
+                    false
+                }
+            })
+            .count();
+
+        Some(count as u32 + 1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let input = crate::read_file("examples", 2022, 14).unwrap();
+        assert_eq!(Day14::solve_part_one(&input), Some(24));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = crate::read_file("examples", 2022, 14).unwrap();
+        assert_eq!(Day14::solve_part_two(&input), Some(93));
+    }
+}
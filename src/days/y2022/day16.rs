@@ -0,0 +1,213 @@
+use std::cell::RefCell;
+use std::collections::{HashMap, HashSet};
+use std::rc::Rc;
+use parsers::parse_input;
+use crate::helpers::WeightedGraph;
+use crate::{Params, Solution};
+
+#[derive(Debug, Clone)]
+pub struct Valve {
+    name: Name,
+    flow: u32,
+    connections: Vec<Name>,
+    timed_connections: RefCell<HashMap<Name, u32>>,
+}
+
+type Name = [u8; 2];
+
+type Valves = HashMap<Name, Valve>;
+
+pub struct Cave {
+    valves: Valves,
+    start: Name,
+    minutes_one: u32,
+    minutes_two: u32,
+}
+
+mod parsers {
+    use std::cell::RefCell;
+    use std::collections::HashMap;
+    use anyhow::{Result};
+    use nom::bytes::complete::{tag};
+    use nom::character::complete::{u32 as u32_fn, line_ending, anychar, multispace0};
+    use nom::combinator::{all_consuming};
+    use nom::multi::separated_list1;
+    use nom::{AsChar, InputLength, InputTakeAtPosition, Parser};
+    use nom::branch::alt;
+    use nom::error::ParseError;
+    use nom::sequence::{delimited, preceded, tuple};
+    use super::{Name, Valve, Valves};
+
+    pub(super) fn parse_input(input: &str) -> Result<Valves> {
+        let (_, res) = parse_all(separated_list1(line_ending, parse_line))(input)
+            .map_err(|e| e.map(|e| nom::error::Error::new(e.input.to_owned(), e.code)))?;
+
+        let result = res.into_iter()
+            .map(|(name, flow, connections)| {
+                Valve {
+                    name,
+                    flow,
+                    connections,
+                    timed_connections: RefCell::new(HashMap::new()),
+                }
+            })
+            .map(|valve| (valve.name, valve))
+            .collect();
+
+        Ok(result)
+    }
+
+    fn parse_all<I, O, E>(parser: impl Parser<I, O, E>) -> impl FnMut(I) -> nom::IResult<I, O, E>
+    where I: InputLength + InputTakeAtPosition,
+          <I as InputTakeAtPosition>::Item: AsChar + Clone,
+          E: ParseError<I>,
+    {
+        all_consuming(delimited(multispace0, parser, multispace0))
+    }
+
+    fn parse_line(input: &str) -> nom::IResult<&str, (Name, u32, Vec<Name>)> {
+        tuple((
+            preceded(tag("Valve "), parse_key),
+            preceded(tag(" has flow rate="), u32_fn),
+            preceded(alt((tag("; tunnels lead to valves "), tag("; tunnel leads to valve "))), separated_list1(
+                tag(", "),
+                parse_key,
+            ))
+        ))(input)
+    }
+
+    fn parse_key(input: &str) -> nom::IResult<&str, Name> {
+        let (input, o1) = anychar(input)?;
+        anychar(input).map(|(i, o2)| (i, [o1 as u8, o2 as u8]))
+    }
+}
+
+/// Compresses the tunnels down to `start` and the valves worth opening, with the distances between them.
+fn build_connections(valves: &Valves, start: Name) {
+    let tunnels: WeightedGraph<Name> = valves.values()
+        .flat_map(|valve| valve.connections.iter().map(move |&conn| (valve.name, conn, 1)))
+        .collect();
+
+    let compressed = tunnels.compress(|name| valves[name].flow > 0 || *name == start);
+
+    for (from, to, distance) in compressed.edges() {
+        if valves[to].flow > 0 {
+            valves[from].timed_connections.borrow_mut().insert(*to, distance);
+        }
+    }
+}
+
+/// `elephant` is where and with how much time the elephant starts, once we're done.
+fn solve(valves: &Valves, name: Name, mut open: Rc<HashSet<Name>>, minutes: u32, mut flow: u32, elephant: Option<(Name, u32)>) -> u32 {
+    if minutes == 0 {
+        return 0;
+    }
+
+    let this = valves.get(&name).unwrap();
+
+    if this.flow > 0 {
+        let mut temp = (*open).clone();
+        temp.insert(this.name);
+        open = Rc::new(temp);
+        flow += this.flow;
+    }
+
+    let mut max = flow * minutes;
+
+    let res = this.timed_connections.borrow().iter()
+        .filter(|(next_name, _)| !open.contains(*next_name))
+        .map(|(n, dist)| (n, dist + 1)) // Time to turn on valve
+        .filter(|(_, distance)| *distance < minutes)
+        .map(|(&next_name, distance)| {
+            distance * flow + solve(
+                valves,
+                next_name,
+                open.clone(),
+                minutes - distance,
+                flow,
+                elephant,
+            )
+        })
+        .max()
+        .unwrap_or(0);
+
+    let res = res.max(max + if let Some((start, minutes)) = elephant {
+        solve(
+            valves,
+            start,
+            open,
+            minutes,
+            0,
+            None,
+        )
+    } else { 0 });
+
+    max = max.max(res);
+
+    max
+}
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    type Input<'a> = Cave;
+    type AnswerOne = u32;
+    type AnswerTwo = u32;
+
+    const PARAMS: &'static [(&'static str, &'static str)] = &[
+        ("start", "AA"),
+        ("minutes_one", "30"),
+        ("minutes_two", "26"),
+    ];
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        Self::parse_with(input, &Self::params())
+    }
+
+    fn parse_with<'a>(input: &'a str, params: &Params) -> Self::Input<'a> {
+        let valves = parse_input(input).expect("couldn't parse input");
+
+        let start: String = params.get("start");
+        let start: Name = start.as_bytes().try_into().expect("start must be a two letter valve name");
+        assert!(valves.contains_key(&start), "start valve {} doesn't exist", String::from_utf8_lossy(&start));
+
+        build_connections(&valves, start);
+
+        Cave {
+            valves,
+            start,
+            minutes_one: params.get("minutes_one"),
+            minutes_two: params.get("minutes_two"),
+        }
+    }
+
+    fn part_one(cave: &Self::Input<'_>) -> Option<u32> {
+        let answer = solve(&cave.valves, cave.start, Rc::new(HashSet::new()), cave.minutes_one, 0, None);
+
+        Some(answer)
+    }
+
+    fn part_two(cave: &Self::Input<'_>) -> Option<u32> {
+        let elephant = Some((cave.start, cave.minutes_two));
+        let answer = solve(&cave.valves, cave.start, Rc::new(HashSet::new()), cave.minutes_two, 0, elephant);
+
+        Some(answer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let input = crate::read_file("examples", 2022, 16).unwrap();
+        assert_eq!(Day16::solve_part_one(&input), Some(1651));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = crate::read_file("examples", 2022, 16).unwrap();
+        assert_eq!(Day16::solve_part_two(&input), Some(1707));
+    }
+}
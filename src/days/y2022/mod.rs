@@ -0,0 +1,24 @@
+/*
+ * Every solved day of 2022 is registered here.
+ * Add a `N => dayNN::DayNN` line to `register!` after creating `./dayNN.rs`.
+ */
+pub const YEAR: u16 = 2022;
+
+register! {
+    1 => day01::Day01,
+    2 => day02::Day02,
+    3 => day03::Day03,
+    4 => day04::Day04,
+    5 => day05::Day05,
+    6 => day06::Day06,
+    7 => day07::Day07,
+    8 => day08::Day08,
+    9 => day09::Day09,
+    10 => day10::Day10,
+    11 => day11::Day11,
+    12 => day12::Day12,
+    13 => day13::Day13,
+    14 => day14::Day14,
+    15 => day15::Day15,
+    16 => day16::Day16,
+}
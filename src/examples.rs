@@ -0,0 +1,185 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use crate::answers::Answers;
+use crate::runner::{Day, RunOptions, Status};
+use crate::{InputSource, Params};
+use serde::Deserialize;
+use std::fmt::{self, Display};
+use std::fs;
+use std::io::{self, ErrorKind};
+use std::path::{Path, PathBuf};
+
+/// An example input together with the answers it should produce.
+///
+/// Examples live in `src/examples/<year>`, either as `NN.txt` or as any `NN/<name>.txt`. Their
+/// expected answers are stored next to them in `NN.json` or `NN/<name>.json`, in the same format
+/// as `src/answers/<year>/NN.json`. Examples without such a sidecar file are not checked.
+///
+/// The sidecar can also override puzzle parameters, e.g. `"params": { "row": "10" }`.
+#[derive(Debug, Clone)]
+pub struct Example {
+    pub year: u16,
+    pub day: u8,
+    pub name: String,
+    pub path: PathBuf,
+    pub expected: Answers,
+    pub params: Params,
+}
+
+#[derive(Deserialize)]
+struct Sidecar {
+    #[serde(flatten)]
+    expected: Answers,
+    #[serde(default)]
+    params: Params,
+}
+
+impl Example {
+    fn load(year: u16, day: u8, name: String, path: PathBuf) -> io::Result<Option<Self>> {
+        let sidecar = path.with_extension("json");
+        if !sidecar.is_file() {
+            return Ok(None);
+        }
+
+        let sidecar: Sidecar = serde_json::from_str(&fs::read_to_string(&sidecar)?)
+            .map_err(|e| io::Error::new(ErrorKind::InvalidData, e))?;
+
+        Ok(Some(Self {
+            year,
+            day,
+            name,
+            path,
+            expected: sidecar.expected,
+            params: sidecar.params,
+        }))
+    }
+
+    /// Runs `day` on this example. Returns a description of every part with an unexpected outcome.
+    pub fn check(&self, day: &Day) -> Result<(), Vec<String>> {
+        let input = InputSource::Path(self.path.clone())
+            .read()
+            .map_err(|e| vec![format!("{}: {}", self, e)])?;
+
+        Params::check(day.params, &self.params).map_err(|e| vec![format!("{}: {}", self, e)])?;
+
+        let options = RunOptions {
+            params: self.params.clone(),
+            ..RunOptions::default()
+        };
+
+        let mut results = (day.solve)(day.year, day.day, &input, &options);
+        self.expected.verify(&mut results);
+
+        let failures: Vec<_> = results
+            .iter()
+            .filter_map(|result| {
+                let expected = result.expected.as_deref()?;
+                let got = match (result.status, &result.answer) {
+                    (Status::Solved, _) => return None,
+                    (Status::Wrong, Some(answer)) => format!("got {:?}", answer),
+                    (Status::Wrong, None) => "got no answer".to_string(),
+                    (status, _) => match &result.message {
+                        Some(message) => format!("{}: {}", status, message),
+                        None => status.to_string(),
+                    },
+                };
+
+                Some(format!(
+                    "{} part {}: expected {:?}, {}",
+                    self, result.part, expected, got
+                ))
+            })
+            .collect();
+
+        match failures.is_empty() {
+            true => Ok(()),
+            false => Err(failures),
+        }
+    }
+}
+
+impl Display for Example {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} day {:02} example \"{}\"",
+            self.year, self.day, self.name
+        )
+    }
+}
+
+/// Finds every example of `day` that has expected answers, see `Example`.
+pub fn discover(year: u16, day: u8) -> io::Result<Vec<Example>> {
+    discover_in(
+        &crate::folder_path("examples").join(year.to_string()),
+        year,
+        day,
+    )
+}
+
+fn discover_in(folder: &Path, year: u16, day: u8) -> io::Result<Vec<Example>> {
+    let padded = format!("{:02}", day);
+    let mut examples = vec![];
+
+    let default = folder.join(format!("{}.txt", padded));
+    if default.is_file() {
+        examples.extend(Example::load(year, day, padded.clone(), default)?);
+    }
+
+    let mut named = match fs::read_dir(folder.join(&padded)) {
+        Ok(entries) => entries
+            .map(|entry| entry.map(|entry| entry.path()))
+            .collect::<io::Result<Vec<_>>>()?,
+        Err(e) if e.kind() == ErrorKind::NotFound => vec![],
+        Err(e) => return Err(e),
+    };
+    named.sort();
+
+    for path in named {
+        if path.extension().is_some_and(|ext| ext == "txt") {
+            let name = path.file_stem().unwrap().to_string_lossy().into_owned();
+            examples.extend(Example::load(year, day, name, path)?);
+        }
+    }
+
+    Ok(examples)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::days;
+
+    #[test]
+    fn test_discover() {
+        let examples = discover(2022, 6).unwrap();
+        assert_eq!(examples[0].name, "06");
+        assert!(examples.iter().any(|example| example.name == "bvwb"));
+        assert!(discover(2022, 25).unwrap().is_empty());
+    }
+
+    /// Checks every discovered example of every registered day, or only of the day named in
+    /// `AOC_EXAMPLES_DAY` like its binary, e.g. `06` or `2023-06`, as `cargo watch-day` does.
+    #[test]
+    fn test_examples() {
+        let only = std::env::var("AOC_EXAMPLES_DAY").ok();
+        let failures: Vec<String> = days::all()
+            .filter(|day| {
+                only.as_ref()
+                    .is_none_or(|name| *name == crate::bin_name(day.year, day.day))
+            })
+            .flat_map(|day| {
+                let examples = discover(day.year, day.day).unwrap();
+                examples
+                    .into_iter()
+                    .filter_map(|example| example.check(day).err())
+                    .flatten()
+                    .collect::<Vec<_>>()
+            })
+            .collect();
+
+        assert!(failures.is_empty(), "\n{}", failures.join("\n"));
+    }
+}
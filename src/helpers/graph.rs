@@ -0,0 +1,394 @@
+use super::search::{self, Graph};
+use std::collections::HashMap;
+use std::fmt::{Display, Write};
+use std::hash::Hash;
+use std::ops::Add;
+
+/// Hands out dense indices for keys, in the order they are first seen.
+#[derive(Debug, Clone)]
+pub struct Interner<K> {
+    indices: HashMap<K, usize>,
+    keys: Vec<K>,
+}
+
+impl<K: Clone + Eq + Hash> Interner<K> {
+    pub fn new() -> Self {
+        Self {
+            indices: HashMap::new(),
+            keys: Vec::new(),
+        }
+    }
+
+    /// Index of `key`, adding it if it's new.
+    pub fn intern(&mut self, key: K) -> usize {
+        if let Some(&index) = self.indices.get(&key) {
+            return index;
+        }
+
+        self.keys.push(key.clone());
+        self.indices.insert(key, self.keys.len() - 1);
+        self.keys.len() - 1
+    }
+
+    pub fn index(&self, key: &K) -> Option<usize> {
+        self.indices.get(key).copied()
+    }
+
+    /// Panics if `index` wasn't handed out by this interner.
+    pub fn key(&self, index: usize) -> &K {
+        &self.keys[index]
+    }
+
+    pub fn keys(&self) -> &[K] {
+        &self.keys
+    }
+
+    pub fn len(&self) -> usize {
+        self.keys.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.keys.is_empty()
+    }
+}
+
+impl<K: Clone + Eq + Hash> Default for Interner<K> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// A directed graph with weighted edges between keyed nodes, e.g. valve names.
+///
+/// Nodes are interned, so searches run on their dense indices. It implements `Graph` on those,
+/// use `index` and `key` to go back and forth.
+#[derive(Debug, Clone)]
+pub struct WeightedGraph<K, C = u32> {
+    nodes: Interner<K>,
+    edges: Vec<Vec<(usize, C)>>,
+}
+
+impl<K, C> WeightedGraph<K, C>
+where
+    K: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+{
+    pub fn new() -> Self {
+        Self {
+            nodes: Interner::new(),
+            edges: Vec::new(),
+        }
+    }
+
+    /// Index of `key`, adding it as a node without edges if it's new.
+    pub fn add_node(&mut self, key: K) -> usize {
+        let index = self.nodes.intern(key);
+        if index == self.edges.len() {
+            self.edges.push(Vec::new());
+        }
+        index
+    }
+
+    /// Adds an edge from `from` to `to`, and the nodes if needed.
+    /// Add the reverse edge as well for undirected graphs.
+    pub fn add_edge(&mut self, from: K, to: K, cost: C) {
+        let from = self.add_node(from);
+        let to = self.add_node(to);
+        self.edges[from].push((to, cost));
+    }
+
+    pub fn index(&self, key: &K) -> Option<usize> {
+        self.nodes.index(key)
+    }
+
+    pub fn key(&self, index: usize) -> &K {
+        self.nodes.key(index)
+    }
+
+    pub fn keys(&self) -> &[K] {
+        self.nodes.keys()
+    }
+
+    pub fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+
+    /// Every edge as `(from, to, cost)`, grouped by `from` in node order.
+    pub fn edges(&self) -> impl Iterator<Item = (&K, &K, C)> {
+        self.edges
+            .iter()
+            .enumerate()
+            .flat_map(move |(from, edges)| {
+                edges
+                    .iter()
+                    .map(move |&(to, cost)| (self.key(from), self.key(to), cost))
+            })
+    }
+
+    /// Shortest distances between every pair of nodes, for any edge costs.
+    pub fn floyd_warshall(&self) -> Distances<C> {
+        let mut distances = Distances::new(self.len());
+
+        for (from, edges) in self.edges.iter().enumerate() {
+            distances.improve(from, from, C::default());
+            for &(to, cost) in edges {
+                distances.improve(from, to, cost);
+            }
+        }
+
+        for via in 0..self.len() {
+            for from in 0..self.len() {
+                let first = match distances.get(from, via) {
+                    Some(first) => first,
+                    None => continue,
+                };
+                for to in 0..self.len() {
+                    if let Some(second) = distances.get(via, to) {
+                        distances.improve(from, to, first + second);
+                    }
+                }
+            }
+        }
+
+        distances
+    }
+
+    /// Fewest edges between every pair of nodes, ignoring their costs, with a BFS from each node.
+    /// Matches `floyd_warshall` when every edge costs 1, and is faster on sparse graphs.
+    pub fn repeated_bfs(&self) -> Distances<usize> {
+        let mut distances = Distances::new(self.len());
+
+        for from in 0..self.len() {
+            for (to, distance) in search::bfs(&Hops(self), [from], |_| false).distances() {
+                distances.improve(from, to, distance);
+            }
+        }
+
+        distances
+    }
+
+    /// A graph of only the nodes where `keep` is true, with an edge between every two of them
+    /// that are connected, costing the shortest distance between them in this graph.
+    pub fn compress(&self, keep: impl Fn(&K) -> bool) -> Self {
+        let kept: Vec<usize> = (0..self.len()).filter(|&i| keep(self.key(i))).collect();
+
+        let mut compressed = Self::new();
+        for &from in &kept {
+            compressed.add_node(self.key(from).clone());
+
+            let reachable = search::dijkstra(self, [from], |_| false);
+            for &to in &kept {
+                if let Some(distance) = reachable.distance_to(to).filter(|_| to != from) {
+                    compressed.add_edge(self.key(from).clone(), self.key(to).clone(), distance);
+                }
+            }
+        }
+
+        compressed
+    }
+
+    /// The graph in Graphviz's DOT format, e.g. to render it with `dot -Tsvg`.
+    pub fn to_dot(&self) -> String
+    where
+        K: Display,
+        C: Display,
+    {
+        let mut output = String::from("digraph {\n");
+        for key in self.keys() {
+            writeln!(output, "    {};", dot_id(key)).unwrap();
+        }
+        for (from, to, cost) in self.edges() {
+            writeln!(
+                output,
+                "    {} -> {} [label={}];",
+                dot_id(from),
+                dot_id(to),
+                cost
+            )
+            .unwrap();
+        }
+        output.push_str("}\n");
+        output
+    }
+}
+
+/// A `WeightedGraph` where every edge costs one hop.
+struct Hops<'a, K, C>(&'a WeightedGraph<K, C>);
+
+impl<K, C> Graph for Hops<'_, K, C> {
+    type Node = usize;
+    type Cost = usize;
+
+    fn neighbours(&self, node: usize) -> impl Iterator<Item = (usize, usize)> {
+        self.0.edges[node].iter().map(|&(next, _)| (next, 1))
+    }
+}
+
+/// `key` as a quoted DOT identifier.
+fn dot_id(key: impl Display) -> String {
+    let escaped = key.to_string().replace('\\', "\\\\").replace('"', "\\\"");
+    format!("\"{}\"", escaped)
+}
+
+impl<K, C> Default for WeightedGraph<K, C>
+where
+    K: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Collects `(from, to, cost)` edges.
+impl<K, C> FromIterator<(K, K, C)> for WeightedGraph<K, C>
+where
+    K: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+{
+    fn from_iter<I: IntoIterator<Item = (K, K, C)>>(iter: I) -> Self {
+        let mut graph = Self::new();
+        for (from, to, cost) in iter {
+            graph.add_edge(from, to, cost);
+        }
+        graph
+    }
+}
+
+impl<K, C> Graph for WeightedGraph<K, C>
+where
+    K: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+{
+    type Node = usize;
+    type Cost = C;
+
+    fn neighbours(&self, node: usize) -> impl Iterator<Item = (usize, C)> {
+        self.edges[node].iter().copied()
+    }
+}
+
+/// Distances between every pair of nodes of a `WeightedGraph`, by node index.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Distances<C> {
+    size: usize,
+    cells: Vec<Option<C>>,
+}
+
+impl<C: Copy + Ord> Distances<C> {
+    fn new(size: usize) -> Self {
+        Self {
+            size,
+            cells: vec![None; size * size],
+        }
+    }
+
+    /// `None` if `to` can't be reached from `from`.
+    pub fn get(&self, from: usize, to: usize) -> Option<C> {
+        self.cells[from * self.size + to]
+    }
+
+    fn improve(&mut self, from: usize, to: usize, distance: C) {
+        let cell = &mut self.cells[from * self.size + to];
+        if cell.is_none_or(|known| distance < known) {
+            *cell = Some(distance);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A -- B -- C -- D, with a shortcut from A to D and a dead end E off of B.
+    fn graph() -> WeightedGraph<&'static str> {
+        let tunnels = [
+            ("A", "B", 1),
+            ("B", "C", 1),
+            ("C", "D", 1),
+            ("A", "D", 5),
+            ("B", "E", 2),
+        ];
+
+        tunnels
+            .into_iter()
+            .flat_map(|(from, to, cost)| [(from, to, cost), (to, from, cost)])
+            .collect()
+    }
+
+    #[test]
+    fn test_interner() {
+        let mut interner = Interner::new();
+        assert_eq!(interner.intern("AA".to_string()), 0);
+        assert_eq!(interner.intern("BB".to_string()), 1);
+        assert_eq!(interner.intern("AA".to_string()), 0);
+
+        assert_eq!(interner.len(), 2);
+        assert_eq!(interner.index(&"BB".to_string()), Some(1));
+        assert_eq!(interner.index(&"CC".to_string()), None);
+        assert_eq!(interner.key(1), "BB");
+    }
+
+    #[test]
+    fn test_all_pairs() {
+        let graph = graph();
+        let [a, b, d, e] = ["A", "B", "D", "E"].map(|key| graph.index(&key).unwrap());
+
+        let weighted = graph.floyd_warshall();
+        assert_eq!(weighted.get(a, a), Some(0));
+        assert_eq!(weighted.get(a, d), Some(3));
+        assert_eq!(weighted.get(e, d), Some(4));
+
+        let hops = graph.repeated_bfs();
+        assert_eq!(hops.get(a, d), Some(1));
+        assert_eq!(hops.get(b, e), Some(1));
+        assert_eq!(hops.get(e, d), Some(3));
+
+        let unweighted: WeightedGraph<&str, usize> =
+            graph.edges().map(|(from, to, _)| (*from, *to, 1)).collect();
+        assert_eq!(unweighted.repeated_bfs(), unweighted.floyd_warshall());
+
+        let mut directed = WeightedGraph::new();
+        directed.add_edge("A", "B", 1);
+        let distances = directed.floyd_warshall();
+        assert_eq!(distances.get(0, 1), Some(1));
+        assert_eq!(distances.get(1, 0), None);
+    }
+
+    #[test]
+    fn test_compress() {
+        let compressed = graph().compress(|key| *key != "B" && *key != "C");
+
+        assert_eq!(compressed.keys(), ["A", "D", "E"]);
+        assert_eq!(
+            compressed.edges().collect::<Vec<_>>(),
+            [
+                (&"A", &"D", 3),
+                (&"A", &"E", 3),
+                (&"D", &"A", 3),
+                (&"D", &"E", 4),
+                (&"E", &"A", 3),
+                (&"E", &"D", 4),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_to_dot() {
+        let graph: WeightedGraph<&str> = [("AA", "BB", 2)].into_iter().collect();
+
+        assert_eq!(
+            graph.to_dot(),
+            "digraph {\n    \"AA\";\n    \"BB\";\n    \"AA\" -> \"BB\" [label=2];\n}\n"
+        );
+
+        let quoted: WeightedGraph<&str> = [(r#"say "hi""#, r"C:\", 1)].into_iter().collect();
+        assert!(quoted
+            .to_dot()
+            .contains(r#"    "say \"hi\"" -> "C:\\" [label=1];"#));
+    }
+}
@@ -0,0 +1,384 @@
+use crate::ANSI_RESET;
+use glam::{IVec2, UVec2};
+use std::fmt::{self, Display};
+use std::ops::{Index, IndexMut};
+
+/// Up, right, down and left, with y growing downwards like the rows of the input.
+pub const DIRECTIONS4: [IVec2; 4] = [
+    IVec2::new(0, -1),
+    IVec2::new(1, 0),
+    IVec2::new(0, 1),
+    IVec2::new(-1, 0),
+];
+
+/// `DIRECTIONS4` and the diagonals, clockwise starting from up.
+pub const DIRECTIONS8: [IVec2; 8] = [
+    IVec2::new(0, -1),
+    IVec2::new(1, -1),
+    IVec2::new(1, 0),
+    IVec2::new(1, 1),
+    IVec2::new(0, 1),
+    IVec2::new(-1, 1),
+    IVec2::new(-1, 0),
+    IVec2::new(-1, -1),
+];
+
+/// A dense rectangular grid, stored row by row.
+///
+/// Cells are addressed with `UVec2` positions, `x` being the column and `y` the row.
+/// Indexing panics outside of the grid, `get` takes an `IVec2` and returns `None` there instead.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct Grid<T> {
+    cells: Vec<T>,
+    size: UVec2,
+}
+
+impl<T> Grid<T> {
+    pub fn new(size: UVec2, fill: T) -> Self
+    where
+        T: Clone,
+    {
+        Self {
+            cells: vec![fill; (size.x * size.y) as usize],
+            size,
+        }
+    }
+
+    /// Panics if `cells` doesn't hold exactly `size.x * size.y` cells.
+    pub fn from_vec(size: UVec2, cells: Vec<T>) -> Self {
+        assert_eq!(
+            cells.len(),
+            (size.x * size.y) as usize,
+            "{} cells don't fill a {}x{} grid",
+            cells.len(),
+            size.x,
+            size.y
+        );
+        Self { cells, size }
+    }
+
+    /// Parses a character map, one row per line. Panics if the rows differ in length.
+    pub fn parse(input: &str, mut cell: impl FnMut(UVec2, char) -> T) -> Self {
+        let mut cells = Vec::with_capacity(input.len());
+        let mut width = None;
+        let mut height = 0;
+
+        for (y, line) in input.lines().enumerate() {
+            let width = *width.get_or_insert(line.chars().count());
+            let start = cells.len();
+
+            cells.extend(
+                line.chars()
+                    .enumerate()
+                    .map(|(x, c)| cell(UVec2::new(x as u32, y as u32), c)),
+            );
+            assert_eq!(
+                cells.len() - start,
+                width,
+                "Uneven row length at line {}",
+                y + 1
+            );
+            height += 1;
+        }
+
+        Self::from_vec(UVec2::new(width.unwrap_or(0) as u32, height), cells)
+    }
+
+    pub fn size(&self) -> UVec2 {
+        self.size
+    }
+
+    pub fn width(&self) -> u32 {
+        self.size.x
+    }
+
+    pub fn height(&self) -> u32 {
+        self.size.y
+    }
+
+    /// `pos` as a `UVec2` if it lies within the grid.
+    pub fn checked(&self, pos: IVec2) -> Option<UVec2> {
+        let inside = pos.x >= 0
+            && pos.y >= 0
+            && (pos.x as u32) < self.size.x
+            && (pos.y as u32) < self.size.y;
+
+        inside.then(|| pos.as_uvec2())
+    }
+
+    pub fn contains(&self, pos: IVec2) -> bool {
+        self.checked(pos).is_some()
+    }
+
+    pub fn get(&self, pos: IVec2) -> Option<&T> {
+        let pos = self.checked(pos)?;
+        Some(&self[pos])
+    }
+
+    pub fn get_mut(&mut self, pos: IVec2) -> Option<&mut T> {
+        let pos = self.checked(pos)?;
+        Some(&mut self[pos])
+    }
+
+    fn offset(&self, pos: UVec2) -> usize {
+        assert!(
+            pos.x < self.size.x && pos.y < self.size.y,
+            "{} is outside of a {}x{} grid",
+            pos,
+            self.size.x,
+            self.size.y
+        );
+        (pos.y * self.size.x + pos.x) as usize
+    }
+
+    /// Every position, row by row.
+    pub fn positions(&self) -> impl Iterator<Item = UVec2> {
+        let width = self.size.x;
+        (0..self.size.y).flat_map(move |y| (0..width).map(move |x| UVec2::new(x, y)))
+    }
+
+    /// Every cell with its position, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (UVec2, &T)> {
+        self.positions().zip(&self.cells)
+    }
+
+    /// Position of the first cell, row by row, that matches `predicate`.
+    pub fn find(&self, predicate: impl Fn(&T) -> bool) -> Option<UVec2> {
+        self.iter()
+            .find(|(_, cell)| predicate(cell))
+            .map(|(pos, _)| pos)
+    }
+
+    fn neighbours<'a>(
+        &'a self,
+        pos: UVec2,
+        directions: &'a [IVec2],
+    ) -> impl Iterator<Item = UVec2> + 'a {
+        directions
+            .iter()
+            .filter_map(move |direction| self.checked(pos.as_ivec2() + *direction))
+    }
+
+    /// Neighbours of `pos` in `DIRECTIONS4` that lie within the grid.
+    pub fn neighbours4(&self, pos: UVec2) -> impl Iterator<Item = UVec2> + '_ {
+        self.neighbours(pos, &DIRECTIONS4)
+    }
+
+    /// Neighbours of `pos` in `DIRECTIONS8` that lie within the grid.
+    pub fn neighbours8(&self, pos: UVec2) -> impl Iterator<Item = UVec2> + '_ {
+        self.neighbours(pos, &DIRECTIONS8)
+    }
+
+    pub fn row(&self, y: u32) -> &[T] {
+        let start = self.offset(UVec2::new(0, y));
+        &self.cells[start..start + self.size.x as usize]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.size.x.max(1) as usize)
+    }
+
+    pub fn column(&self, x: u32) -> impl Iterator<Item = &T> {
+        self.cells
+            .iter()
+            .skip(self.offset(UVec2::new(x, 0)))
+            .step_by(self.size.x as usize)
+    }
+
+    /// Cells from `from` in `direction` up to the edge of the grid, not including `from` itself.
+    pub fn ray(&self, from: UVec2, direction: IVec2) -> impl Iterator<Item = (UVec2, &T)> {
+        assert_ne!(direction, IVec2::ZERO, "a ray needs a direction");
+
+        let mut pos = from.as_ivec2();
+        std::iter::from_fn(move || {
+            pos += direction;
+            let pos = self.checked(pos)?;
+            Some((pos, &self[pos]))
+        })
+    }
+
+    pub fn map<U>(&self, mut f: impl FnMut(UVec2, &T) -> U) -> Grid<U> {
+        Grid {
+            cells: self.iter().map(|(pos, cell)| f(pos, cell)).collect(),
+            size: self.size,
+        }
+    }
+
+    /// Builds a grid of `size` where each cell is taken from `source` in `self`.
+    fn remap(&self, size: UVec2, source: impl Fn(UVec2) -> UVec2) -> Self
+    where
+        T: Clone,
+    {
+        let cells = (0..size.y)
+            .flat_map(|y| (0..size.x).map(move |x| UVec2::new(x, y)))
+            .map(|pos| self[source(pos)].clone())
+            .collect();
+
+        Self { cells, size }
+    }
+
+    /// Mirrors the grid along its main diagonal, so rows become columns.
+    pub fn transpose(&self) -> Self
+    where
+        T: Clone,
+    {
+        self.remap(UVec2::new(self.size.y, self.size.x), |pos| {
+            UVec2::new(pos.y, pos.x)
+        })
+    }
+
+    /// Rotates the grid a quarter turn clockwise.
+    pub fn rotate_right(&self) -> Self
+    where
+        T: Clone,
+    {
+        let height = self.size.y;
+        self.remap(UVec2::new(self.size.y, self.size.x), |pos| {
+            UVec2::new(pos.y, height - 1 - pos.x)
+        })
+    }
+
+    /// Rotates the grid a quarter turn counter-clockwise.
+    pub fn rotate_left(&self) -> Self
+    where
+        T: Clone,
+    {
+        let width = self.size.x;
+        self.remap(UVec2::new(self.size.y, self.size.x), |pos| {
+            UVec2::new(width - 1 - pos.y, pos.x)
+        })
+    }
+
+    /// Like `Display`, but prints the cells where `marked` is true in `colour`,
+    /// one of the `ANSI_*` constants, e.g. to show which cells a search visited.
+    pub fn to_string_highlighted(&self, colour: &str, marked: impl Fn(UVec2) -> bool) -> String
+    where
+        T: Display,
+    {
+        let mut output = String::new();
+        for (pos, cell) in self.iter() {
+            match marked(pos) {
+                true => output.push_str(&format!("{}{}{}", colour, cell, ANSI_RESET)),
+                false => output.push_str(&cell.to_string()),
+            }
+            if pos.x == self.size.x - 1 {
+                output.push('\n');
+            }
+        }
+        output
+    }
+}
+
+impl<T> Index<UVec2> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: UVec2) -> &T {
+        &self.cells[self.offset(pos)]
+    }
+}
+
+impl<T> IndexMut<UVec2> for Grid<T> {
+    fn index_mut(&mut self, pos: UVec2) -> &mut T {
+        let offset = self.offset(pos);
+        &mut self.cells[offset]
+    }
+}
+
+/// Prints every cell with its own `Display`, one row per line.
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ANSI_GREEN;
+
+    const MAP: &str = "ab\ncd\nef\n";
+
+    fn grid() -> Grid<char> {
+        Grid::parse(MAP, |_, c| c)
+    }
+
+    #[test]
+    fn test_parse_index() {
+        let mut grid = grid();
+
+        assert_eq!(grid.size(), UVec2::new(2, 3));
+        assert_eq!(grid[UVec2::new(1, 2)], 'f');
+        assert_eq!(grid.get(IVec2::new(-1, 0)), None);
+        assert_eq!(grid.get(IVec2::new(2, 0)), None);
+        assert_eq!(grid.find(|c| *c == 'd'), Some(UVec2::new(1, 1)));
+
+        *grid.get_mut(IVec2::new(0, 0)).unwrap() = 'z';
+        assert_eq!(grid.to_string(), "zb\ncd\nef\n");
+
+        let positions = Grid::parse("..\n.#", |pos, _| pos);
+        assert_eq!(positions[UVec2::new(1, 1)], UVec2::new(1, 1));
+    }
+
+    #[test]
+    #[should_panic(expected = "Uneven row length at line 2")]
+    fn test_parse_uneven() {
+        Grid::parse("ab\nc", |_, c| c);
+    }
+
+    #[test]
+    #[should_panic(expected = "outside of a 2x3 grid")]
+    fn test_index_outside() {
+        let _ = grid()[UVec2::new(2, 0)];
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let grid = grid();
+
+        let corner: Vec<_> = grid.neighbours4(UVec2::new(0, 0)).collect();
+        assert_eq!(corner, [UVec2::new(1, 0), UVec2::new(0, 1)]);
+        assert_eq!(grid.neighbours4(UVec2::new(1, 1)).count(), 3);
+        assert_eq!(grid.neighbours8(UVec2::new(0, 1)).count(), 5);
+    }
+
+    #[test]
+    fn test_lines() {
+        let grid = grid();
+
+        assert_eq!(grid.row(1), ['c', 'd']);
+        assert_eq!(grid.column(1).collect::<String>(), "bdf");
+        assert_eq!(
+            grid.ray(UVec2::new(0, 0), IVec2::new(0, 1))
+                .map(|(_, c)| *c)
+                .collect::<String>(),
+            "ce"
+        );
+        assert_eq!(grid.ray(UVec2::new(1, 0), IVec2::new(1, 1)).count(), 0);
+    }
+
+    #[test]
+    fn test_transform() {
+        let grid = grid();
+
+        assert_eq!(grid.transpose().to_string(), "ace\nbdf\n");
+        assert_eq!(grid.rotate_right().to_string(), "eca\nfdb\n");
+        assert_eq!(grid.rotate_left().to_string(), "bdf\nace\n");
+        assert_eq!(grid.rotate_right().rotate_left(), grid);
+        assert_eq!(grid.map(|pos, _| pos.x + pos.y).to_string(), "01\n12\n23\n");
+    }
+
+    #[test]
+    fn test_highlighted() {
+        let highlighted = grid().to_string_highlighted(ANSI_GREEN, |pos| pos == UVec2::new(1, 0));
+        assert_eq!(
+            highlighted,
+            format!("a{}b{}\ncd\nef\n", ANSI_GREEN, ANSI_RESET)
+        );
+    }
+}
@@ -0,0 +1,13 @@
+/*
+ * Use this module if you want to extract helpers from your solutions.
+ * Example import from this module: `use advent_of_code::helpers::Grid;`.
+ */
+pub mod graph;
+mod grid;
+pub mod search;
+mod sparse;
+
+pub use graph::{Interner, WeightedGraph};
+pub use grid::{Grid, DIRECTIONS4, DIRECTIONS8};
+pub use search::{Graph, Search};
+pub use sparse::{Bounds, SparseGrid};
@@ -0,0 +1,267 @@
+use priority_queue::PriorityQueue;
+use std::cmp::Reverse;
+use std::collections::hash_map::Entry;
+use std::collections::{HashMap, VecDeque};
+use std::hash::Hash;
+use std::ops::Add;
+
+/// Something that can be searched with `bfs`, `dijkstra` or `astar`.
+pub trait Graph {
+    type Node: Copy + Eq + Hash;
+    type Cost: Copy + Ord + Default + Add<Output = Self::Cost>;
+
+    /// The nodes reachable from `node` in one step, and what that step costs.
+    fn neighbours(&self, node: Self::Node) -> impl Iterator<Item = (Self::Node, Self::Cost)>;
+
+    /// Estimated cost from `node` to the closest goal, used by `astar`.
+    /// It must never overestimate for `astar` to find the cheapest path.
+    fn heuristic(&self, _node: Self::Node) -> Self::Cost {
+        Self::Cost::default()
+    }
+}
+
+/// Outcome of a search: every node it reached, how, and the goal it stopped at.
+#[derive(Debug, Clone)]
+pub struct Search<N, C> {
+    /// Cheapest known cost of each reached node, and the node it was reached from.
+    visited: HashMap<N, (C, Option<N>)>,
+    goal: Option<N>,
+}
+
+impl<N: Copy + Eq + Hash, C: Copy> Search<N, C> {
+    /// The goal the search stopped at, `None` if no goal was reachable.
+    pub fn goal(&self) -> Option<N> {
+        self.goal
+    }
+
+    /// Cost from the closest start to the goal.
+    pub fn distance(&self) -> Option<C> {
+        self.distance_to(self.goal?)
+    }
+
+    /// Nodes from the start to the goal, both included.
+    pub fn path(&self) -> Option<Vec<N>> {
+        self.path_to(self.goal?)
+    }
+
+    /// Cost of the cheapest path found to `node`. Only final for nodes the search got past,
+    /// like the goal or any node after a search without goal.
+    pub fn distance_to(&self, node: N) -> Option<C> {
+        self.visited.get(&node).map(|(cost, _)| *cost)
+    }
+
+    pub fn path_to(&self, node: N) -> Option<Vec<N>> {
+        let mut path = vec![node];
+        let mut current = node;
+        while let Some(previous) = self.visited.get(&current)?.1 {
+            path.push(previous);
+            current = previous;
+        }
+        path.reverse();
+        Some(path)
+    }
+
+    pub fn is_visited(&self, node: N) -> bool {
+        self.visited.contains_key(&node)
+    }
+
+    /// Every reached node with its cost, in no particular order.
+    pub fn distances(&self) -> impl Iterator<Item = (N, C)> + '_ {
+        self.visited.iter().map(|(node, (cost, _))| (*node, *cost))
+    }
+}
+
+/// Breadth-first search from all `starts` until a node matches `is_goal`.
+/// Assumes every step costs the same, use `dijkstra` otherwise.
+pub fn bfs<G: Graph>(
+    graph: &G,
+    starts: impl IntoIterator<Item = G::Node>,
+    is_goal: impl Fn(G::Node) -> bool,
+) -> Search<G::Node, G::Cost> {
+    let mut visited = HashMap::new();
+    let mut queue = VecDeque::new();
+
+    for start in starts {
+        visited.insert(start, (G::Cost::default(), None));
+        queue.push_back(start);
+    }
+
+    while let Some(node) = queue.pop_front() {
+        if is_goal(node) {
+            return Search {
+                visited,
+                goal: Some(node),
+            };
+        }
+
+        let cost = visited[&node].0;
+        for (next, step) in graph.neighbours(node) {
+            if let Entry::Vacant(entry) = visited.entry(next) {
+                entry.insert((cost + step, Some(node)));
+                queue.push_back(next);
+            }
+        }
+    }
+
+    Search {
+        visited,
+        goal: None,
+    }
+}
+
+/// Cheapest path from any of `starts` to a node matching `is_goal`.
+pub fn dijkstra<G: Graph>(
+    graph: &G,
+    starts: impl IntoIterator<Item = G::Node>,
+    is_goal: impl Fn(G::Node) -> bool,
+) -> Search<G::Node, G::Cost> {
+    best_first(graph, starts, is_goal, |_| G::Cost::default())
+}
+
+/// Like `dijkstra`, but explores the nodes the `Graph::heuristic` deems closest to a goal first.
+pub fn astar<G: Graph>(
+    graph: &G,
+    starts: impl IntoIterator<Item = G::Node>,
+    is_goal: impl Fn(G::Node) -> bool,
+) -> Search<G::Node, G::Cost> {
+    best_first(graph, starts, is_goal, |node| graph.heuristic(node))
+}
+
+fn best_first<G: Graph>(
+    graph: &G,
+    starts: impl IntoIterator<Item = G::Node>,
+    is_goal: impl Fn(G::Node) -> bool,
+    heuristic: impl Fn(G::Node) -> G::Cost,
+) -> Search<G::Node, G::Cost> {
+    let mut visited = HashMap::new();
+    let mut queue = PriorityQueue::new();
+
+    for start in starts {
+        visited.insert(start, (G::Cost::default(), None));
+        queue.push(start, Reverse(heuristic(start)));
+    }
+
+    while let Some((node, _)) = queue.pop() {
+        if is_goal(node) {
+            return Search {
+                visited,
+                goal: Some(node),
+            };
+        }
+
+        let cost = visited[&node].0;
+        for (next, step) in graph.neighbours(node) {
+            let next_cost = cost + step;
+            if matches!(visited.get(&next), Some((known, _)) if *known <= next_cost) {
+                continue;
+            }
+
+            visited.insert(next, (next_cost, Some(node)));
+            queue.push_increase(next, Reverse(next_cost + heuristic(next)));
+        }
+    }
+
+    Search {
+        visited,
+        goal: None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::helpers::Grid;
+    use glam::UVec2;
+
+    /// A maze where walls are `#`, every step costs 1 and the digits cost their value to enter.
+    struct Maze(Grid<char>);
+
+    impl Graph for Maze {
+        type Node = UVec2;
+        type Cost = u32;
+
+        fn neighbours(&self, node: UVec2) -> impl Iterator<Item = (UVec2, u32)> {
+            self.0
+                .neighbours4(node)
+                .filter(|next| self.0[*next] != '#')
+                .map(|next| (next, self.0[next].to_digit(10).unwrap_or(1)))
+        }
+
+        fn heuristic(&self, node: UVec2) -> u32 {
+            let goal = self.0.find(|c| *c == 'E').unwrap();
+            let offset = (node.as_ivec2() - goal.as_ivec2()).abs();
+            (offset.x + offset.y) as u32
+        }
+    }
+
+    const MAZE: &str = "\
+S.9.E
+.#.#.
+.....
+";
+
+    fn maze() -> (Maze, UVec2) {
+        let grid = Grid::parse(MAZE, |_, c| c);
+        let start = grid.find(|c| *c == 'S').unwrap();
+        (Maze(grid), start)
+    }
+
+    #[test]
+    fn test_bfs() {
+        let (maze, start) = maze();
+        let search = bfs(&maze, [start], |node| maze.0[node] == 'E');
+
+        assert_eq!(search.goal(), Some(UVec2::new(4, 0)));
+        assert_eq!(search.path().unwrap().len(), 5);
+
+        let everything = bfs(&maze, [start], |_| false);
+        assert_eq!(everything.goal(), None);
+        assert_eq!(everything.distances().count(), 13);
+        assert!(!everything.is_visited(UVec2::new(1, 1)));
+    }
+
+    #[test]
+    fn test_dijkstra_astar() {
+        let (maze, start) = maze();
+        let is_goal = |node: UVec2| maze.0[node] == 'E';
+
+        let expected: Vec<UVec2> = [
+            (0, 0),
+            (0, 1),
+            (0, 2),
+            (1, 2),
+            (2, 2),
+            (3, 2),
+            (4, 2),
+            (4, 1),
+            (4, 0),
+        ]
+        .into_iter()
+        .map(|(x, y)| UVec2::new(x, y))
+        .collect();
+
+        let cheapest = dijkstra(&maze, [start], is_goal);
+        assert_eq!(cheapest.distance(), Some(8));
+        assert_eq!(cheapest.path(), Some(expected.clone()));
+
+        let guided = astar(&maze, [start], is_goal);
+        assert_eq!(guided.distance(), Some(8));
+        assert_eq!(guided.path(), Some(expected));
+        assert!(guided.distances().count() <= cheapest.distances().count());
+    }
+
+    #[test]
+    fn test_multiple_starts() {
+        let (maze, _) = maze();
+        let starts = [UVec2::new(0, 2), UVec2::new(2, 1)];
+        let search = dijkstra(&maze, starts, |node| node == UVec2::new(2, 2));
+
+        assert_eq!(search.distance(), Some(1));
+        assert_eq!(
+            search.path(),
+            Some(vec![UVec2::new(2, 1), UVec2::new(2, 2)])
+        );
+        assert_eq!(search.distance_to(UVec2::new(0, 2)), Some(0));
+        assert_eq!(search.path_to(UVec2::new(9, 9)), None);
+    }
+}
@@ -0,0 +1,282 @@
+use super::Grid;
+use glam::{IVec2, UVec2};
+use std::collections::HashMap;
+use std::fmt::{self, Display};
+
+/// An inclusive rectangle of positions.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct Bounds {
+    pub min: IVec2,
+    pub max: IVec2,
+}
+
+impl Bounds {
+    /// The bounds of a single position.
+    pub fn new(pos: IVec2) -> Self {
+        Self { min: pos, max: pos }
+    }
+
+    /// The smallest bounds holding all `positions`, `None` if there are none.
+    pub fn from_positions(positions: impl IntoIterator<Item = IVec2>) -> Option<Self> {
+        let mut positions = positions.into_iter();
+        let first = Self::new(positions.next()?);
+        Some(positions.fold(first, |bounds, pos| bounds.expanded(pos)))
+    }
+
+    /// Grows the bounds so they hold `pos`.
+    pub fn expanded(self, pos: IVec2) -> Self {
+        Self {
+            min: self.min.min(pos),
+            max: self.max.max(pos),
+        }
+    }
+
+    /// Grows the bounds by `amount` in every direction.
+    pub fn padded(self, amount: i32) -> Self {
+        Self {
+            min: self.min - amount,
+            max: self.max + amount,
+        }
+    }
+
+    pub fn size(&self) -> UVec2 {
+        (self.max - self.min + 1).as_uvec2()
+    }
+
+    pub fn area(&self) -> u64 {
+        let size = self.size();
+        size.x as u64 * size.y as u64
+    }
+
+    pub fn contains(&self, pos: IVec2) -> bool {
+        pos.cmpge(self.min).all() && pos.cmple(self.max).all()
+    }
+
+    /// Every position inside the bounds, row by row.
+    pub fn positions(&self) -> impl Iterator<Item = IVec2> {
+        let (min, max) = (self.min, self.max);
+        (min.y..=max.y).flat_map(move |y| (min.x..=max.x).map(move |x| IVec2::new(x, y)))
+    }
+}
+
+/// A grid on an unbounded plane, only storing the cells that were set.
+///
+/// Keeps track of the bounding box of every cell that was ever inserted. Removing cells
+/// doesn't shrink it, use `tight_bounds` for the bounds of the remaining cells.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct SparseGrid<T> {
+    cells: HashMap<IVec2, T>,
+    bounds: Option<Bounds>,
+}
+
+impl<T> SparseGrid<T> {
+    pub fn new() -> Self {
+        Self {
+            cells: HashMap::new(),
+            bounds: None,
+        }
+    }
+
+    /// Parses a character map like `Grid::parse`, keeping the cells for which `cell` returns some value.
+    pub fn parse(input: &str, mut cell: impl FnMut(IVec2, char) -> Option<T>) -> Self {
+        input
+            .lines()
+            .enumerate()
+            .flat_map(|(y, line)| {
+                line.chars()
+                    .enumerate()
+                    .map(move |(x, c)| (IVec2::new(x as i32, y as i32), c))
+            })
+            .filter_map(|(pos, c)| Some((pos, cell(pos, c)?)))
+            .collect()
+    }
+
+    /// Sets a cell, growing the bounds if needed. Returns the previous value.
+    pub fn insert(&mut self, pos: IVec2, value: T) -> Option<T> {
+        self.bounds = Some(match self.bounds {
+            Some(bounds) => bounds.expanded(pos),
+            None => Bounds::new(pos),
+        });
+        self.cells.insert(pos, value)
+    }
+
+    pub fn remove(&mut self, pos: IVec2) -> Option<T> {
+        self.cells.remove(&pos)
+    }
+
+    pub fn get(&self, pos: IVec2) -> Option<&T> {
+        self.cells.get(&pos)
+    }
+
+    pub fn get_mut(&mut self, pos: IVec2) -> Option<&mut T> {
+        self.cells.get_mut(&pos)
+    }
+
+    pub fn contains(&self, pos: IVec2) -> bool {
+        self.cells.contains_key(&pos)
+    }
+
+    /// Number of cells that are set.
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    /// The cells that are set, in no particular order.
+    pub fn iter(&self) -> impl Iterator<Item = (IVec2, &T)> {
+        self.cells.iter().map(|(pos, cell)| (*pos, cell))
+    }
+
+    pub fn positions(&self) -> impl Iterator<Item = IVec2> + '_ {
+        self.cells.keys().copied()
+    }
+
+    /// Bounding box of every cell inserted so far, `None` if nothing was.
+    pub fn bounds(&self) -> Option<Bounds> {
+        self.bounds
+    }
+
+    /// Bounding box of the cells that are currently set.
+    pub fn tight_bounds(&self) -> Option<Bounds> {
+        Bounds::from_positions(self.positions())
+    }
+
+    /// Converts the bounding box into a dense `Grid`, with `fill` for the cells that aren't set.
+    /// Cell `pos` ends up at `pos - bounds.min`. `None` if the grid would be empty or hold
+    /// more than `max_cells` cells.
+    pub fn to_grid(&self, fill: T, max_cells: u64) -> Option<Grid<T>>
+    where
+        T: Clone,
+    {
+        let bounds = self.bounds?;
+        if bounds.area() > max_cells {
+            return None;
+        }
+
+        let cells = bounds
+            .positions()
+            .map(|pos| self.get(pos).unwrap_or(&fill).clone())
+            .collect();
+
+        Some(Grid::from_vec(bounds.size(), cells))
+    }
+
+    /// Draws `region` one row per line, with `cell` choosing the character of each position.
+    pub fn render(&self, region: Bounds, cell: impl Fn(IVec2, Option<&T>) -> char) -> String {
+        let mut output = String::new();
+        for pos in region.positions() {
+            output.push(cell(pos, self.get(pos)));
+            if pos.x == region.max.x {
+                output.push('\n');
+            }
+        }
+        output
+    }
+}
+
+impl<T> Default for SparseGrid<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> FromIterator<(IVec2, T)> for SparseGrid<T> {
+    fn from_iter<I: IntoIterator<Item = (IVec2, T)>>(iter: I) -> Self {
+        let mut grid = Self::new();
+        grid.extend(iter);
+        grid
+    }
+}
+
+impl<T> Extend<(IVec2, T)> for SparseGrid<T> {
+    fn extend<I: IntoIterator<Item = (IVec2, T)>>(&mut self, iter: I) {
+        for (pos, value) in iter {
+            self.insert(pos, value);
+        }
+    }
+}
+
+/// Prints the bounding box with every cell's own `Display`, and `.` for the ones that aren't set.
+impl<T: Display> Display for SparseGrid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let bounds = match self.bounds {
+            Some(bounds) => bounds,
+            None => return Ok(()),
+        };
+
+        for pos in bounds.positions() {
+            match self.get(pos) {
+                Some(cell) => write!(f, "{}", cell)?,
+                None => write!(f, ".")?,
+            }
+            if pos.x == bounds.max.x {
+                writeln!(f)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bounds() {
+        let mut grid = SparseGrid::new();
+        assert_eq!(grid.bounds(), None);
+        assert_eq!(grid.to_string(), "");
+
+        grid.insert(IVec2::new(2, -1), '#');
+        grid.insert(IVec2::new(-1, 1), 'o');
+        assert_eq!(grid.insert(IVec2::new(2, -1), '~'), Some('#'));
+
+        let bounds = grid.bounds().unwrap();
+        assert_eq!(bounds.min, IVec2::new(-1, -1));
+        assert_eq!(bounds.max, IVec2::new(2, 1));
+        assert_eq!(bounds.size(), UVec2::new(4, 3));
+        assert_eq!(bounds.area(), 12);
+        assert!(bounds.contains(IVec2::new(0, 0)));
+        assert!(!bounds.contains(IVec2::new(3, 0)));
+        assert_eq!(grid.to_string(), "...~\n....\no...\n");
+
+        assert_eq!(grid.remove(IVec2::new(2, -1)), Some('~'));
+        assert_eq!(grid.len(), 1);
+        assert_eq!(grid.bounds(), Some(bounds));
+        assert_eq!(grid.tight_bounds(), Some(Bounds::new(IVec2::new(-1, 1))));
+    }
+
+    #[test]
+    fn test_to_grid() {
+        let grid = SparseGrid::parse(".#.\n..#\n", |_, c| (c == '#').then_some(true));
+        assert_eq!(grid.len(), 2);
+
+        let dense = grid.to_grid(false, 4).unwrap();
+        assert_eq!(dense.size(), UVec2::new(2, 2));
+        assert!(dense[UVec2::new(0, 0)]);
+        assert!(!dense[UVec2::new(1, 0)]);
+        assert!(dense[UVec2::new(1, 1)]);
+
+        assert_eq!(grid.to_grid(false, 3), None);
+        assert_eq!(SparseGrid::<bool>::new().to_grid(false, 4), None);
+    }
+
+    #[test]
+    fn test_render() {
+        let grid: SparseGrid<()> = [IVec2::new(0, 0), IVec2::new(1, 1)]
+            .into_iter()
+            .map(|pos| (pos, ()))
+            .collect();
+
+        let region = grid.bounds().unwrap().padded(1);
+        let rendered = grid.render(region, |pos, cell| match (pos, cell) {
+            (_, Some(_)) => '#',
+            (pos, None) if pos == IVec2::new(2, 2) => 'x',
+            _ => '.',
+        });
+        assert_eq!(rendered, "....\n.#..\n..#.\n...x\n");
+    }
+}
@@ -0,0 +1,219 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use crate::runner::{serialize_nanos, PartResult, Status};
+use serde::{Deserialize, Deserializer, Serialize};
+use std::fs::{self, OpenOptions};
+use std::io::{self, ErrorKind, Write};
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// Timing of one part in one run, a line of `src/history/runs.jsonl`.
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+pub struct Entry {
+    /// `git describe --always --dirty` at the time of the run.
+    pub commit: String,
+    /// Seconds since the unix epoch.
+    pub timestamp: u64,
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    #[serde(
+        rename = "elapsed_ns",
+        serialize_with = "serialize_nanos",
+        deserialize_with = "deserialize_nanos"
+    )]
+    pub elapsed: Duration,
+    #[serde(
+        rename = "parse_ns",
+        serialize_with = "serialize_nanos",
+        deserialize_with = "deserialize_nanos"
+    )]
+    pub parse: Duration,
+    #[serde(default)]
+    pub parallel: bool,
+}
+
+fn deserialize_nanos<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Duration, D::Error> {
+    u64::deserialize(deserializer).map(Duration::from_nanos)
+}
+
+impl Entry {
+    /// Entries of the solved parts of `results`.
+    pub fn from_results(results: &[PartResult], commit: &str, timestamp: u64) -> Vec<Self> {
+        results
+            .iter()
+            .filter(|result| matches!(result.status, Status::Solved | Status::Wrong))
+            .map(|result| Self {
+                commit: commit.to_string(),
+                timestamp,
+                year: result.year,
+                day: result.day,
+                part: result.part,
+                elapsed: result.elapsed,
+                parse: result.parse,
+                parallel: result.parallel,
+            })
+            .collect()
+    }
+}
+
+pub fn path() -> PathBuf {
+    crate::folder_path("history").join("runs.jsonl")
+}
+
+/// Current commit, or "unknown" outside of a git checkout.
+pub fn current_commit() -> String {
+    Command::new("git")
+        .args(["describe", "--always", "--dirty"])
+        .output()
+        .ok()
+        .filter(|output| output.status.success())
+        .and_then(|output| String::from_utf8(output.stdout).ok())
+        .map(|commit| commit.trim().to_string())
+        .filter(|commit| !commit.is_empty())
+        .unwrap_or_else(|| "unknown".to_string())
+}
+
+pub fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |elapsed| elapsed.as_secs())
+}
+
+/// Loads every recorded entry, oldest first. A missing file means no history yet.
+pub fn load(path: &Path) -> io::Result<Vec<Entry>> {
+    let contents = match fs::read_to_string(path) {
+        Ok(contents) => contents,
+        Err(e) if e.kind() == ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(e) => return Err(e),
+    };
+
+    contents
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| {
+            serde_json::from_str(line).map_err(|e| io::Error::new(ErrorKind::InvalidData, e))
+        })
+        .collect()
+}
+
+pub fn append(path: &Path, entries: &[Entry]) -> io::Result<()> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+
+    let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+    for entry in entries {
+        writeln!(file, "{}", serde_json::to_string(entry)?)?;
+    }
+
+    Ok(())
+}
+
+/// Timing of a part in the current run next to its baseline.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Comparison {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    pub baseline: Duration,
+    pub current: Duration,
+}
+
+impl Comparison {
+    /// Relative change in percent, positive when the part got slower.
+    pub fn change(&self) -> f64 {
+        let baseline = self.baseline.as_secs_f64();
+        match baseline > 0.0 {
+            true => (self.current.as_secs_f64() - baseline) / baseline * 100.0,
+            false => 0.0,
+        }
+    }
+
+    pub fn is_regression(&self, threshold: f64) -> bool {
+        self.change() > threshold
+    }
+}
+
+/// Compares `current` against the latest entry of each part recorded at a commit starting with
+/// `baseline`, or at any commit for "last". Entries are only compared with the same mode
+/// (parallel or not), and parts without a baseline are left out.
+pub fn compare(history: &[Entry], baseline: &str, current: &[Entry]) -> Vec<Comparison> {
+    current
+        .iter()
+        .filter_map(|entry| {
+            let base = history.iter().rev().find(|base| {
+                (base.year, base.day, base.part, base.parallel)
+                    == (entry.year, entry.day, entry.part, entry.parallel)
+                    && (baseline == "last" || base.commit.starts_with(baseline))
+            })?;
+
+            Some(Comparison {
+                year: entry.year,
+                day: entry.day,
+                part: entry.part,
+                baseline: base.elapsed,
+                current: entry.elapsed,
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(commit: &str, part: u8, elapsed_ms: u64) -> Entry {
+        Entry {
+            commit: commit.to_string(),
+            timestamp: 1670000000,
+            year: 2022,
+            day: 15,
+            part,
+            elapsed: Duration::from_millis(elapsed_ms),
+            parse: Duration::ZERO,
+            parallel: false,
+        }
+    }
+
+    #[test]
+    fn test_append_load() {
+        let path = std::env::temp_dir()
+            .join(format!("aoc-history-{}", std::process::id()))
+            .join("runs.jsonl");
+        let entries = vec![entry("abc1234", 1, 36), entry("abc1234", 2, 40)];
+
+        assert_eq!(load(&path).unwrap(), vec![]);
+        append(&path, &entries[..1]).unwrap();
+        append(&path, &entries[1..]).unwrap();
+        assert_eq!(load(&path).unwrap(), entries);
+
+        fs::remove_dir_all(path.parent().unwrap()).unwrap();
+    }
+
+    #[test]
+    fn test_compare() {
+        let history = vec![
+            entry("abc1234", 1, 3600000),
+            entry("abc1234", 2, 100),
+            entry("def5678", 1, 40),
+        ];
+        let current = vec![entry("0f0f0f0", 1, 36), entry("0f0f0f0", 2, 105)];
+
+        let last = compare(&history, "last", &current);
+        assert_eq!(last.len(), 2);
+        assert_eq!(last[0].baseline, Duration::from_millis(40));
+        assert!(!last[0].is_regression(10.0));
+        assert!(last[1].is_regression(4.0));
+        assert!(!last[1].is_regression(10.0));
+
+        let old = compare(&history, "abc", &current);
+        assert_eq!(old[0].baseline, Duration::from_secs(3600));
+        assert!(old[0].change() < -99.0);
+
+        assert!(compare(&history, "123", &current).is_empty());
+    }
+}
@@ -1,94 +1,173 @@
 /*
  * This file contains template code.
  * There is no need to edit this file unless you want to change template functionality.
- * Prefer `./helpers.rs` if you want to extract code from your solutions.
+ * Prefer `./helpers/` if you want to extract code from your solutions.
  */
 use std::env;
+use std::error::Error;
+use std::ffi::OsString;
+use std::fmt::{self, Display};
 use std::fs;
-
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+pub mod answers;
+pub mod bench;
+pub mod days;
+pub mod examples;
 pub mod helpers;
+pub mod history;
+pub mod memory;
+pub mod params;
+pub mod runner;
+pub mod solution;
+
+pub use params::Params;
+pub use solution::Solution;
 
 pub const ANSI_ITALIC: &str = "\x1b[3m";
 pub const ANSI_BOLD: &str = "\x1b[1m";
+pub const ANSI_GREEN: &str = "\x1b[32m";
 pub const ANSI_RESET: &str = "\x1b[0m";
 
-#[macro_export]
-macro_rules! solve {
-    ($part:expr, $solver:ident, $input:expr) => {{
-        use advent_of_code::{ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
-        use std::fmt::Display;
-        use std::time::Instant;
-
-        fn print_result<T: Display>(func: impl FnOnce(&str) -> Option<T>, input: &str) {
-            let timer = Instant::now();
-            let result = func(input);
-            let elapsed = timer.elapsed();
-            match result {
-                Some(result) => {
-                    println!(
-                        "{} {}(elapsed: {:.2?}){}",
-                        result, ANSI_ITALIC, elapsed, ANSI_RESET
-                    );
-                }
-                None => {
-                    println!("not solved.")
-                }
-            }
-        }
+/// Year used when none is given, e.g. by `cargo scaffold`, see `default_year`.
+pub const DEFAULT_YEAR: u16 = 2022;
+
+/// The year this repository started with. Its binaries keep their short `NN` names, and its
+/// inputs may still be in the flat `src/inputs/NN.txt` layout from before years had directories.
+pub const FIRST_YEAR: u16 = 2022;
+
+/// `AOC_YEAR` if set, `DEFAULT_YEAR` otherwise.
+pub fn default_year() -> u16 {
+    env::var("AOC_YEAR")
+        .ok()
+        .and_then(|year| year.parse().ok())
+        .unwrap_or(DEFAULT_YEAR)
+}
+
+/// Name of the binary that runs `day`, e.g. `cargo solve 07` or `cargo solve 2023-07`.
+pub fn bin_name(year: u16, day: u8) -> String {
+    match year {
+        FIRST_YEAR => format!("{:02}", day),
+        _ => format!("{}-{:02}", year, day),
+    }
+}
+
+/// Directory holding the files of `folder`.
+///
+/// `AOC_<FOLDER>_DIR` (e.g. `AOC_INPUTS_DIR`, `AOC_EXAMPLES_DIR`) overrides it, otherwise it is
+/// `src/<folder>` of this crate, no matter where the binary is run from.
+pub fn folder_path(folder: &str) -> PathBuf {
+    resolve_folder(folder, env::var_os(folder_env(folder)))
+}
+
+/// Name of the environment variable overriding `folder_path(folder)`.
+pub fn folder_env(folder: &str) -> String {
+    format!("AOC_{}_DIR", folder.to_uppercase())
+}
 
-        println!("🎄 {}Part {}{} 🎄", ANSI_BOLD, $part, ANSI_RESET);
-        print_result($solver, $input);
-    }};
+fn resolve_folder(folder: &str, dir: Option<OsString>) -> PathBuf {
+    match dir {
+        Some(dir) if !dir.is_empty() => PathBuf::from(dir),
+        _ => Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("src")
+            .join(folder),
+    }
 }
 
-pub fn read_file(folder: &str, day: u8) -> String {
-    let cwd = env::current_dir().unwrap();
+/// `<year>/NN.txt` inside `root`, falling back to the flat layout for `FIRST_YEAR`.
+pub fn day_path(root: &Path, year: u16, day: u8) -> PathBuf {
+    let file_name = format!("{:02}.txt", day);
+    let path = root.join(year.to_string()).join(&file_name);
+    let legacy = root.join(&file_name);
 
-    let filepath = cwd.join("src").join(folder).join(format!("{:02}.txt", day));
+    match year == FIRST_YEAR && !path.exists() && legacy.exists() {
+        true => legacy,
+        false => path,
+    }
+}
 
-    let f = fs::read_to_string(filepath);
-    f.expect("could not open input file")
+pub fn file_path(folder: &str, year: u16, day: u8) -> PathBuf {
+    day_path(&folder_path(folder), year, day)
 }
 
-fn parse_time(val: &str, postfix: &str) -> f64 {
-    val.split(postfix).next().unwrap().parse().unwrap()
+/// Reads `<year>/NN.txt` of `folder`, see `folder_path`.
+pub fn read_file(folder: &str, year: u16, day: u8) -> Result<String, InputError> {
+    InputSource::Path(file_path(folder, year, day)).read()
 }
 
-pub fn parse_exec_time(output: &str) -> f64 {
-    output.lines().fold(0_f64, |acc, l| {
-        if !l.contains("elapsed:") {
-            acc
-        } else {
-            let timing = l.split("(elapsed: ").last().unwrap();
-            // use `contains` istd. of `ends_with`: string may contain ANSI escape sequences.
-            // for possible time formats, see: https://github.com/rust-lang/rust/blob/1.64.0/library/core/src/time.rs#L1176-L1200
-            if timing.contains("ns)") {
-                acc // range below rounding precision.
-            } else if timing.contains("µs)") {
-                acc + parse_time(timing, "µs") / 1000_f64
-            } else if timing.contains("ms)") {
-                acc + parse_time(timing, "ms")
-            } else if timing.contains("s)") {
-                acc + parse_time(timing, "s") * 1000_f64
-            } else {
-                acc
+/// Where a puzzle input is read from. Parses `-` as stdin and anything else as a path.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum InputSource {
+    Path(PathBuf),
+    Stdin,
+}
+
+impl InputSource {
+    pub fn read(&self) -> Result<String, InputError> {
+        let result = match self {
+            InputSource::Path(path) => fs::read_to_string(path),
+            InputSource::Stdin => {
+                let mut input = String::new();
+                io::stdin().read_to_string(&mut input).map(|_| input)
             }
+        };
+
+        result.map_err(|error| InputError {
+            input: self.clone(),
+            error,
+        })
+    }
+}
+
+impl FromStr for InputSource {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "" => Err("input path is empty".to_string()),
+            "-" => Ok(InputSource::Stdin),
+            path => Ok(InputSource::Path(PathBuf::from(path))),
         }
-    })
+    }
 }
 
-/// copied from: https://github.com/rust-lang/rust/blob/1.64.0/library/std/src/macros.rs#L328-L333
-#[cfg(test)]
-macro_rules! assert_approx_eq {
-    ($a:expr, $b:expr) => {{
-        let (a, b) = (&$a, &$b);
-        assert!(
-            (*a - *b).abs() < 1.0e-6,
-            "{} is not approximately equal to {}",
-            *a,
-            *b
-        );
-    }};
+impl Display for InputSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InputSource::Path(path) => write!(f, "\"{}\"", path.display()),
+            InputSource::Stdin => write!(f, "stdin"),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct InputError {
+    pub input: InputSource,
+    pub error: io::Error,
+}
+
+impl InputError {
+    pub fn is_not_found(&self) -> bool {
+        self.error.kind() == io::ErrorKind::NotFound
+    }
+}
+
+impl Display for InputError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "could not read input from {}: {}",
+            self.input, self.error
+        )
+    }
+}
+
+impl Error for InputError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        Some(&self.error)
+    }
 }
 
 #[cfg(test)]
@@ -96,30 +175,54 @@ mod tests {
     use super::*;
 
     #[test]
-    fn test_parse_exec_time() {
-        assert_approx_eq!(
-            parse_exec_time(&format!(
-                "🎄 Part 1 🎄\n0 (elapsed: 74.13ns){}\n🎄 Part 2 🎄\n0 (elapsed: 50.00ns){}",
-                ANSI_RESET, ANSI_RESET
-            )),
-            0_f64
+    fn test_resolve_folder() {
+        let default = Path::new(env!("CARGO_MANIFEST_DIR")).join("src/inputs");
+        assert_eq!(resolve_folder("inputs", None), default);
+        assert_eq!(resolve_folder("inputs", Some("".into())), default);
+        assert_eq!(
+            resolve_folder("inputs", Some("/tmp/aoc".into())),
+            PathBuf::from("/tmp/aoc")
         );
+        assert_eq!(folder_env("examples"), "AOC_EXAMPLES_DIR");
+    }
 
-        assert_approx_eq!(
-            parse_exec_time("🎄 Part 1 🎄\n0 (elapsed: 755µs)\n🎄 Part 2 🎄\n0 (elapsed: 700µs)"),
-            1.455_f64
+    #[test]
+    fn test_read_file_error() {
+        let error = read_file("examples", 2022, 99).unwrap_err();
+        assert!(error.is_not_found());
+        assert_eq!(
+            error.input,
+            InputSource::Path(file_path("examples", 2022, 99))
         );
+        assert!(error.to_string().contains("2022/99.txt"));
+    }
 
-        assert_approx_eq!(
-            parse_exec_time("🎄 Part 1 🎄\n0 (elapsed: 70µs)\n🎄 Part 2 🎄\n0 (elapsed: 1.45ms)"),
-            1.52_f64
-        );
+    #[test]
+    fn test_day_path() {
+        let root = env::temp_dir().join(format!("aoc-day-path-test-{}", std::process::id()));
+        fs::create_dir_all(root.join("2023")).unwrap();
+        fs::write(root.join("01.txt"), "").unwrap();
 
-        assert_approx_eq!(
-            parse_exec_time(
-                "🎄 Part 1 🎄\n0 (elapsed: 10.3s)\n🎄 Part 2 🎄\n0 (elapsed: 100.50ms)"
-            ),
-            10400.50_f64
+        assert_eq!(day_path(&root, 2022, 1), root.join("01.txt"));
+        assert_eq!(day_path(&root, 2023, 1), root.join("2023/01.txt"));
+
+        fs::create_dir_all(root.join("2022")).unwrap();
+        fs::write(root.join("2022/01.txt"), "").unwrap();
+        assert_eq!(day_path(&root, 2022, 1), root.join("2022/01.txt"));
+
+        fs::remove_dir_all(&root).unwrap();
+
+        assert_eq!(bin_name(2022, 7), "07");
+        assert_eq!(bin_name(2023, 7), "2023-07");
+    }
+
+    #[test]
+    fn test_input_source_from_str() {
+        assert_eq!("-".parse(), Ok(InputSource::Stdin));
+        assert_eq!(
+            "foo/bar.txt".parse(),
+            Ok(InputSource::Path(PathBuf::from("foo/bar.txt")))
         );
+        assert!("".parse::<InputSource>().is_err());
     }
 }
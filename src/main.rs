@@ -2,45 +2,359 @@
  * This file contains template code.
  * There is no need to edit this file unless you want to change template functionality.
  */
-use advent_of_code::{ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
-use std::process::Command;
+use advent_of_code::bench::Stats;
+use advent_of_code::runner::{self, DaySelection, Format, PartResult, RunOptions, Status};
+use advent_of_code::{days, ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
+use itertools::Itertools;
+use rayon::prelude::*;
+use std::time::Duration;
+use std::{env, process};
+
+const SEQUENTIAL_ENV: &str = "AOC_SEQUENTIAL";
+
+/// Flags of `cargo all` on top of `RunOptions`.
+struct Args {
+    /// `--year` may be repeated, all registered years are run without it.
+    years: Vec<u16>,
+    /// Only run these days, e.g. `cargo all 3..7` or `cargo all 1,5,12`.
+    days: DaySelection,
+    /// Skip days without a registered module instead of reporting them as not solved.
+    only_solved: bool,
+    /// List the N days that took the longest after the totals.
+    slowest: Option<usize>,
+    /// Run days concurrently. Their timings include contention and are marked as such.
+    parallel: bool,
+}
+
+fn parse_args(args: &mut pico_args::Arguments) -> Result<Args, pico_args::Error> {
+    Ok(Args {
+        years: args.values_from_str("--year")?,
+        only_solved: args.contains("--only-solved"),
+        slowest: args.opt_value_from_str("--slowest")?,
+        parallel: args.contains("--parallel"),
+        days: args.opt_free_from_fn(parse_days)?.unwrap_or_default(),
+    })
+}
+
+/// Parses the day selection, which comes last, so a leftover flag is reported as such.
+fn parse_days(s: &str) -> Result<DaySelection, String> {
+    match s.starts_with('-') {
+        true => Err("unknown flag".to_string()),
+        false => s.parse(),
+    }
+}
 
 fn main() {
-    let total: f64 = (1..=25)
-        .map(|day| {
-            let day = format!("{:02}", day);
-
-            let cmd = Command::new("cargo")
-                .args(["run", "--release", "--bin", &day])
-                .output()
-                .unwrap();
-
-            println!("----------");
-            println!("{}| Day {} |{}", ANSI_BOLD, day, ANSI_RESET);
-            println!("----------");
-
-            let output = String::from_utf8(cmd.stdout).unwrap();
-            let is_empty = output.is_empty();
-
-            println!(
-                "{}",
-                if is_empty {
-                    "Not solved."
-                } else {
-                    output.trim()
-                }
+    let (options, args) = RunOptions::from_env_with(parse_args);
+    let format = options.format;
+
+    let years: Vec<u16> = match args.years.is_empty() {
+        true => days::years().collect(),
+        false => args.years,
+    };
+
+    if options.input.is_some() || !options.params.is_empty() {
+        eprintln!("`--input` and `--param` only work for a single day, use `cargo solve` instead.");
+        process::exit(1);
+    }
+
+    let selected: Vec<(u16, u8)> = years
+        .into_iter()
+        .cartesian_product(1..=25)
+        .filter(|(_, day)| args.days.contains(*day))
+        .filter(|(year, day)| !args.only_solved || days::get(*year, *day).is_some())
+        .collect();
+
+    let parallel = match (args.parallel, sequential_reason(&options)) {
+        (true, Some(reason)) => {
+            eprintln!(
+                "Running days one at a time despite `--parallel`, {}.",
+                reason
             );
+            false
+        }
+        (parallel, _) => parallel,
+    };
+
+    let results: Vec<PartResult> = match parallel {
+        true => {
+            // Days finish in any order, so their output is held back until all of them are done.
+            let results: Vec<Vec<PartResult>> = selected
+                .par_iter()
+                .map(|(year, day)| solve(*year, *day, &options))
+                .collect();
+
+            results
+                .into_iter()
+                .flat_map(|mut day_results| {
+                    day_results
+                        .iter_mut()
+                        .for_each(|result| result.parallel = true);
+                    if format == Format::Human {
+                        print_day(&day_results);
+                    }
+                    day_results
+                })
+                .collect()
+        }
+        false => selected
+            .into_iter()
+            .flat_map(|(year, day)| {
+                let day_results = solve(year, day, &options);
+                if format == Format::Human {
+                    print_day(&day_results);
+                }
+                day_results
+            })
+            .collect(),
+    };
+
+    if format != Format::Human {
+        runner::print_results(format, &results);
+    } else {
+        print_totals(&results);
+        print_summary(&results);
+
+        if let Some(count) = args.slowest {
+            print_slowest(&results, count);
+        }
+    }
+
+    let regressed = runner::track_history(&results, &options);
 
-            if is_empty {
-                0_f64
-            } else {
-                advent_of_code::parse_exec_time(&output)
-            }
+    if runner::has_wrong_answers(&results) || regressed {
+        process::exit(1);
+    }
+}
+
+/// Why `--parallel` can't be honoured, if it can't. Benchmarks compete for cores too, and
+/// allocation counts are process-wide, so both always run one day at a time. `AOC_SEQUENTIAL`
+/// overrides `--parallel` when accurate timings matter.
+fn sequential_reason(options: &RunOptions) -> Option<String> {
+    if options.bench.is_some() {
+        Some("since `--bench` needs the cores to itself".to_string())
+    } else if options.alloc {
+        Some("since `--alloc` counts allocations of the whole process".to_string())
+    } else if env::var_os(SEQUENTIAL_ENV).is_some_and(|value| !value.is_empty()) {
+        Some(format!("since {} is set", SEQUENTIAL_ENV))
+    } else {
+        None
+    }
+}
+
+/// Runs the selected parts of a day, reporting them as unsolved if the day or its input is missing.
+fn solve(year: u16, day: u8, options: &RunOptions) -> Vec<PartResult> {
+    let entry = days::get(year, day);
+    let input = entry.map(|_| options.input_source(year, day).read());
+
+    // A missing input just means the day is not solved, anything else is worth a warning.
+    if let Some(Err(e)) = &input {
+        if !e.is_not_found() {
+            eprintln!("Skipping {} day {}: {}", year, day, e);
+        }
+    }
+
+    match (entry, input) {
+        (Some(entry), Some(Ok(input))) => runner::run_day(entry, &input, options),
+        _ => [1, 2]
+            .into_iter()
+            .filter(|part| options.runs_part(*part))
+            .map(|part| PartResult::unsolved(year, day, part))
+            .collect(),
+    }
+}
+
+fn print_day(day_results: &[PartResult]) {
+    let Some(first) = day_results.first() else {
+        return;
+    };
+
+    println!("---------------");
+    println!(
+        "{}| {} Day {:02} |{}",
+        ANSI_BOLD, first.year, first.day, ANSI_RESET
+    );
+    println!("---------------");
+
+    if day_results
+        .iter()
+        .all(|result| result.status == Status::Unsolved)
+    {
+        println!("Not solved.");
+    } else {
+        runner::print_day(day_results);
+    }
+}
+
+fn same_day(a: &PartResult, b: &PartResult) -> bool {
+    (a.year, a.day) == (b.year, b.day)
+}
+
+fn format_elapsed(elapsed: Option<Duration>) -> String {
+    match elapsed {
+        Some(elapsed) => format!("{:.2?}", elapsed),
+        None => "-".to_string(),
+    }
+}
+
+/// Parse time of a day, and the time of each part that gave an answer.
+fn day_timings(day_results: &[PartResult]) -> (Duration, [Option<Duration>; 2]) {
+    let mut parts = [None; 2];
+    for result in day_results {
+        parts[result.part as usize - 1] = result.answer.as_ref().map(|_| result.elapsed);
+    }
+
+    (day_results[0].parse, parts)
+}
+
+/// Elapsed time of a solved part, its status otherwise.
+fn part_cell(day_results: &[PartResult], part: u8) -> String {
+    let result = day_results.iter().find(|result| result.part == part);
+    match result.map(|result| (result.status, result.elapsed)) {
+        Some((Status::Solved, elapsed)) => format_elapsed(Some(elapsed)),
+        Some((Status::Wrong, _)) => "wrong".to_string(),
+        Some((Status::Panicked, _)) => "panicked".to_string(),
+        Some((Status::TimedOut, _)) => "timed out".to_string(),
+        Some((Status::Unsolved, _)) | None => format_elapsed(None),
+    }
+}
+
+fn print_row(day_results: &[PartResult]) {
+    println!(
+        "{:>7} | {:>10} | {:>10} | {:>10}",
+        format!("{}/{:02}", day_results[0].year, day_results[0].day),
+        format_elapsed(Some(day_results[0].parse)),
+        part_cell(day_results, 1),
+        part_cell(day_results, 2)
+    );
+}
+
+/// Prints parse, part 1 and part 2 timings of every attempted day as separate columns, followed by totals.
+/// Parts without an answer show their status instead, and only count towards the totals with one.
+fn print_totals(results: &[PartResult]) {
+    let mut parse_total = Duration::ZERO;
+    let mut part_totals = [Duration::ZERO; 2];
+
+    println!("---------------");
+    println!(
+        "{:>7} | {:>10} | {:>10} | {:>10}",
+        "Day", "Parse", "Part 1", "Part 2"
+    );
+
+    for day_results in results.chunk_by(same_day) {
+        if day_results
+            .iter()
+            .all(|result| result.status == Status::Unsolved)
+        {
+            continue;
+        }
+
+        let (parse, parts) = day_timings(day_results);
+        parse_total += parse;
+
+        for (total, elapsed) in part_totals.iter_mut().zip(&parts) {
+            *total += elapsed.unwrap_or_default();
+        }
+
+        print_row(day_results);
+    }
+
+    let total = parse_total + part_totals[0] + part_totals[1];
+
+    if results.iter().any(|result| result.parallel) {
+        println!(
+            "{}Timings were measured with days running in parallel.{}",
+            ANSI_ITALIC, ANSI_RESET
+        );
+    }
+
+    println!(
+        "{}Total:{} {}{:.2}ms (parse: {:.2?}, part 1: {:.2?}, part 2: {:.2?}){}",
+        ANSI_BOLD,
+        ANSI_RESET,
+        ANSI_ITALIC,
+        total.as_secs_f64() * 1000_f64,
+        parse_total,
+        part_totals[0],
+        part_totals[1],
+        ANSI_RESET
+    );
+
+    let parse_stats = results
+        .chunk_by(same_day)
+        .filter_map(|day_results| day_results[0].parse_bench.as_ref());
+    let part_stats = results.iter().filter_map(|result| result.bench.as_ref());
+
+    if let Some(stats) = Stats::total(parse_stats.chain(part_stats)) {
+        println!(
+            "{}Bench total:{} {}{}{}",
+            ANSI_BOLD, ANSI_RESET, ANSI_ITALIC, stats, ANSI_RESET
+        );
+    }
+}
+
+/// Prints the `count` solved days with the highest parse and part times combined, slowest first.
+fn print_slowest(results: &[PartResult], count: usize) {
+    let slowest = results
+        .chunk_by(same_day)
+        .filter(|day_results| day_results.iter().any(|result| result.answer.is_some()))
+        .map(|day_results| {
+            let (parse, parts) = day_timings(day_results);
+            let total = parse + parts.iter().flatten().sum::<Duration>();
+            (total, day_results)
         })
-        .sum();
+        .sorted_by(|a, b| b.0.cmp(&a.0))
+        .take(count);
 
+    println!("---------------");
+    println!("{}Slowest days:{}", ANSI_BOLD, ANSI_RESET);
     println!(
-        "{}Total:{} {}{:.2}ms{}",
-        ANSI_BOLD, ANSI_RESET, ANSI_ITALIC, total, ANSI_RESET
+        "{:>7} | {:>10} | {:>10} | {:>10}",
+        "Day", "Parse", "Part 1", "Part 2"
     );
+
+    for (_, day_results) in slowest {
+        print_row(day_results);
+    }
+}
+
+/// Counts the parts of every status and lists the ones that need a look.
+fn print_summary(results: &[PartResult]) {
+    let count = |status: Status| {
+        results
+            .iter()
+            .filter(|result| result.status == status)
+            .count()
+    };
+
+    println!("---------------");
+    println!(
+        "{}Parts:{} {} solved, {} wrong, {} panicked, {} timed out, {} not solved",
+        ANSI_BOLD,
+        ANSI_RESET,
+        count(Status::Solved),
+        count(Status::Wrong),
+        count(Status::Panicked),
+        count(Status::TimedOut),
+        count(Status::Unsolved)
+    );
+
+    for result in results {
+        let message = result.message.as_deref().unwrap_or("no message");
+        let detail = match result.status {
+            Status::Solved | Status::Unsolved => continue,
+            Status::Wrong => format!(
+                "wrong answer {}, expected {}",
+                result.answer.as_deref().unwrap_or("none"),
+                result.expected.as_deref().unwrap_or_default()
+            ),
+            Status::Panicked => format!("panicked: {}", message),
+            Status::TimedOut => format!("timed out: {}", message),
+        };
+
+        println!(
+            "{}/{:02} part {}: {}",
+            result.year, result.day, result.part, detail
+        );
+    }
 }
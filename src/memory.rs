@@ -0,0 +1,188 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use serde::Serialize;
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::fmt::{self, Display};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+/// Wraps the system allocator and counts what goes through it.
+/// Installed as the global allocator with the `alloc-stats` feature, see `ENABLED`.
+pub struct Counting {
+    allocations: AtomicUsize,
+    bytes: AtomicUsize,
+    live: AtomicUsize,
+    peak: AtomicUsize,
+}
+
+impl Counting {
+    pub const fn new() -> Self {
+        Self {
+            allocations: AtomicUsize::new(0),
+            bytes: AtomicUsize::new(0),
+            live: AtomicUsize::new(0),
+            peak: AtomicUsize::new(0),
+        }
+    }
+
+    fn add(&self, size: usize) {
+        self.allocations.fetch_add(1, Ordering::Relaxed);
+        self.bytes.fetch_add(size, Ordering::Relaxed);
+        let live = self.live.fetch_add(size, Ordering::Relaxed) + size;
+        self.peak.fetch_max(live, Ordering::Relaxed);
+    }
+
+    fn remove(&self, size: usize) {
+        self.live.fetch_sub(size, Ordering::Relaxed);
+    }
+
+    /// Starts a measurement, the counts are relative to this point.
+    pub fn start(&self) -> Measurement {
+        let live = self.live.load(Ordering::Relaxed);
+        self.peak.store(live, Ordering::Relaxed);
+
+        Measurement {
+            allocations: self.allocations.load(Ordering::Relaxed),
+            bytes: self.bytes.load(Ordering::Relaxed),
+            live,
+        }
+    }
+
+    pub fn stop(&self, start: &Measurement) -> AllocStats {
+        AllocStats {
+            allocations: self.allocations.load(Ordering::Relaxed) - start.allocations,
+            bytes: self.bytes.load(Ordering::Relaxed) - start.bytes,
+            peak_bytes: self.peak.load(Ordering::Relaxed).saturating_sub(start.live),
+        }
+    }
+}
+
+impl Default for Counting {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+unsafe impl GlobalAlloc for Counting {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() {
+            self.add(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc_zeroed(layout);
+        if !ptr.is_null() {
+            self.add(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        self.remove(layout.size());
+    }
+
+    /// Counted as freeing the old block and allocating the new one.
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = System.realloc(ptr, layout, new_size);
+        if !new_ptr.is_null() {
+            self.remove(layout.size());
+            self.add(new_size);
+        }
+        new_ptr
+    }
+}
+
+/// Counts at the start of a measurement.
+pub struct Measurement {
+    allocations: usize,
+    bytes: usize,
+    live: usize,
+}
+
+/// Allocations made while running a part.
+#[derive(Debug, Copy, Clone, Default, Eq, PartialEq, Serialize)]
+pub struct AllocStats {
+    pub allocations: usize,
+    /// Total size of all allocations, including ones that were freed again.
+    pub bytes: usize,
+    /// Most bytes that were live at once, on top of what was live before the part started.
+    pub peak_bytes: usize,
+}
+
+fn format_bytes(bytes: usize) -> String {
+    match bytes {
+        0..=1023 => format!("{} B", bytes),
+        1024..=1048575 => format!("{:.1} KiB", bytes as f64 / 1024.0),
+        _ => format!("{:.1} MiB", bytes as f64 / 1048576.0),
+    }
+}
+
+impl Display for AllocStats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} allocs, {} allocated, {} peak",
+            self.allocations,
+            format_bytes(self.bytes),
+            format_bytes(self.peak_bytes)
+        )
+    }
+}
+
+/// Whether `ALLOCATOR` is the global allocator, so `start` and `stop` see every allocation.
+pub const ENABLED: bool = cfg!(feature = "alloc-stats");
+
+#[cfg_attr(feature = "alloc-stats", global_allocator)]
+pub static ALLOCATOR: Counting = Counting::new();
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_counting() {
+        let counting = Counting::new();
+        let small = Layout::from_size_align(16, 8).unwrap();
+        let large = Layout::from_size_align(4096, 8).unwrap();
+
+        unsafe {
+            let kept = counting.alloc(small);
+            let start = counting.start();
+
+            let ptr = counting.alloc(large);
+            let ptr = counting.realloc(ptr, large, 8192);
+            counting.dealloc(ptr, Layout::from_size_align(8192, 8).unwrap());
+            let freed = counting.alloc_zeroed(small);
+            counting.dealloc(freed, small);
+
+            assert_eq!(
+                counting.stop(&start),
+                AllocStats {
+                    allocations: 3,
+                    bytes: 4096 + 8192 + 16,
+                    peak_bytes: 8192,
+                }
+            );
+
+            counting.dealloc(kept, small);
+        }
+    }
+
+    #[test]
+    fn test_display() {
+        let stats = AllocStats {
+            allocations: 12,
+            bytes: 3 * 1048576,
+            peak_bytes: 1536,
+        };
+        assert_eq!(
+            stats.to_string(),
+            "12 allocs, 3.0 MiB allocated, 1.5 KiB peak"
+        );
+    }
+}
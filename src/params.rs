@@ -0,0 +1,128 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fmt::Display;
+use std::str::FromStr;
+
+/// Puzzle parameters of a day, e.g. the row day 15 looks at.
+///
+/// Days declare their parameters with the defaults for the real input in `Solution::PARAMS`.
+/// They can be overridden with `--param name=value` or in the `params` of an example's sidecar.
+#[derive(Debug, Clone, Default, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Params(BTreeMap<String, String>);
+
+impl Params {
+    pub fn new(defaults: &[(&str, &str)]) -> Self {
+        defaults
+            .iter()
+            .map(|(name, value)| (name.to_string(), value.to_string()))
+            .collect()
+    }
+
+    pub fn with(mut self, name: &str, value: &str) -> Self {
+        self.0.insert(name.to_string(), value.to_string());
+        self
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.0.keys().map(String::as_str)
+    }
+
+    /// Overrides the values of `self` with the ones in `overrides`.
+    pub fn extend(&mut self, overrides: &Params) {
+        self.0.extend(overrides.0.clone());
+    }
+
+    /// Errors if `overrides` contains a parameter that is not in `declared`.
+    pub fn check(declared: &[(&str, &str)], overrides: &Params) -> Result<(), String> {
+        match overrides
+            .names()
+            .find(|name| declared.iter().all(|(declared, _)| declared != name))
+        {
+            None => Ok(()),
+            Some(name) if declared.is_empty() => {
+                Err(format!("unknown parameter \"{}\", this day has none", name))
+            }
+            Some(name) => Err(format!(
+                "unknown parameter \"{}\", expected one of: {}",
+                name,
+                declared
+                    .iter()
+                    .map(|(name, _)| *name)
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            )),
+        }
+    }
+
+    /// Parses the value of `name`. Panics if it is missing or invalid.
+    pub fn get<T>(&self, name: &str) -> T
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        let value = self
+            .0
+            .get(name)
+            .unwrap_or_else(|| panic!("missing parameter \"{}\"", name));
+
+        value
+            .parse()
+            .unwrap_or_else(|e| panic!("invalid value \"{}\" for \"{}\": {}", value, name, e))
+    }
+}
+
+impl FromIterator<(String, String)> for Params {
+    fn from_iter<I: IntoIterator<Item = (String, String)>>(iter: I) -> Self {
+        Self(iter.into_iter().collect())
+    }
+}
+
+/// Parses a `name=value` flag.
+pub fn parse_param(s: &str) -> Result<(String, String), String> {
+    match s.split_once('=') {
+        Some((name, value)) if !name.is_empty() => Ok((name.to_string(), value.to_string())),
+        _ => Err(format!("invalid parameter \"{}\", expected name=value", s)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DECLARED: &[(&str, &str)] = &[("row", "2000000"), ("start", "AA")];
+
+    #[test]
+    fn test_params() {
+        let mut params = Params::new(DECLARED);
+        let overrides: Params = [parse_param("row=10").unwrap()].into_iter().collect();
+
+        assert!(Params::check(DECLARED, &overrides).is_ok());
+        params.extend(&overrides);
+
+        assert_eq!(params.get::<i32>("row"), 10);
+        assert_eq!(params.get::<String>("start"), "AA");
+
+        let unknown = Params::default().with("rows", "10");
+        assert!(Params::check(DECLARED, &unknown).is_err());
+        assert!(Params::check(&[], &unknown).is_err());
+    }
+
+    #[test]
+    fn test_parse_param() {
+        assert_eq!(
+            parse_param("start=A=B"),
+            Ok(("start".to_string(), "A=B".to_string()))
+        );
+        assert!(parse_param("row").is_err());
+        assert!(parse_param("=10").is_err());
+    }
+}
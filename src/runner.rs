@@ -0,0 +1,1003 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use crate::answers::Answers;
+use crate::bench::{self, BenchOptions, Stats};
+use crate::history::{self, Comparison, Entry};
+use crate::memory::{self, AllocStats};
+use crate::params::{self, Params};
+use crate::{days, InputSource, Solution, ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
+use serde::{Serialize, Serializer};
+use std::any::Any;
+use std::cell::Cell;
+use std::fmt::{self, Display};
+use std::ops::RangeInclusive;
+use std::panic::{self, AssertUnwindSafe};
+use std::path::PathBuf;
+use std::process;
+use std::str::FromStr;
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::sync::Once;
+use std::thread;
+use std::time::{Duration, Instant};
+
+pub type Answer = Box<dyn Display>;
+
+/// A registered day, see `days::YEARS`.
+pub struct Day {
+    pub year: u16,
+    pub day: u8,
+    /// `Solution::PARAMS` of the day.
+    pub params: &'static [(&'static str, &'static str)],
+    /// `run_solution` for the day's `Solution`.
+    pub solve: fn(u16, u8, &str, &RunOptions) -> Vec<PartResult>,
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Status {
+    Solved,
+    Unsolved,
+    Panicked,
+    /// Solved, but the answer differs from the one in `src/answers/<year>/NN.json`.
+    Wrong,
+    /// Gave up on after `--time-limit`.
+    #[serde(rename = "timed_out")]
+    TimedOut,
+}
+
+impl Display for Status {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Status::Solved => write!(f, "solved"),
+            Status::Unsolved => write!(f, "unsolved"),
+            Status::Panicked => write!(f, "panicked"),
+            Status::Wrong => write!(f, "wrong"),
+            Status::TimedOut => write!(f, "timed_out"),
+        }
+    }
+}
+
+/// Outcome of running one part, as emitted by `--format json` and `--format csv`.
+#[derive(Debug, Clone, Serialize)]
+pub struct PartResult {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    pub answer: Option<String>,
+    #[serde(rename = "elapsed_ns", serialize_with = "serialize_nanos")]
+    pub elapsed: Duration,
+    pub status: Status,
+    /// Time spent in `Solution::parse`. It runs once per day, so both parts share this value.
+    #[serde(rename = "parse_ns", serialize_with = "serialize_nanos")]
+    pub parse: Duration,
+    /// Why the part panicked or timed out.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+    /// Accepted answer, only set when verifying.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expected: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bench: Option<Stats>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parse_bench: Option<Stats>,
+    /// Allocations of the part, only set with `--alloc`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub alloc: Option<AllocStats>,
+    /// Measured while other days ran concurrently, so `elapsed` and `parse` include contention.
+    #[serde(skip_serializing_if = "is_false")]
+    pub parallel: bool,
+}
+
+fn is_false(value: &bool) -> bool {
+    !value
+}
+
+pub(crate) fn serialize_nanos<S: Serializer>(
+    elapsed: &Duration,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    serializer.serialize_u64(elapsed.as_nanos() as u64)
+}
+
+impl PartResult {
+    pub fn unsolved(year: u16, day: u8, part: u8) -> Self {
+        Self {
+            year,
+            day,
+            part,
+            answer: None,
+            elapsed: Duration::ZERO,
+            status: Status::Unsolved,
+            parse: Duration::ZERO,
+            message: None,
+            expected: None,
+            bench: None,
+            parse_bench: None,
+            alloc: None,
+            parallel: false,
+        }
+    }
+
+    pub fn panicked(year: u16, day: u8, part: u8, message: String) -> Self {
+        Self {
+            status: Status::Panicked,
+            message: Some(message),
+            ..Self::unsolved(year, day, part)
+        }
+    }
+
+    pub fn timed_out(year: u16, day: u8, part: u8, message: String) -> Self {
+        Self {
+            status: Status::TimedOut,
+            message: Some(message),
+            ..Self::unsolved(year, day, part)
+        }
+    }
+
+    /// Whether the part has an accepted answer it didn't reproduce, only known after verifying.
+    pub fn is_mismatch(&self) -> bool {
+        self.expected.is_some() && self.status != Status::Solved
+    }
+}
+
+#[derive(Debug, Copy, Clone, Default, Eq, PartialEq)]
+pub enum Format {
+    #[default]
+    Human,
+    Json,
+    Csv,
+}
+
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "human" => Ok(Self::Human),
+            "json" => Ok(Self::Json),
+            "csv" => Ok(Self::Csv),
+            _ => Err(format!(
+                "unknown format \"{}\", expected human, json or csv",
+                s
+            )),
+        }
+    }
+}
+
+/// Flags shared by `cargo solve` and `cargo all`.
+#[derive(Debug, Clone, Default)]
+pub struct RunOptions {
+    pub format: Format,
+    pub bench: Option<BenchOptions>,
+    /// Compare answers against `src/answers/<year>/NN.json`.
+    pub verify: bool,
+    /// Store answers in `src/answers/<year>/NN.json` as accepted.
+    pub record: bool,
+    /// Read inputs from this directory instead of `folder_path("inputs")`.
+    pub inputs_dir: Option<PathBuf>,
+    /// Read the input from this file (or stdin) instead of the day's input file.
+    pub input: Option<InputSource>,
+    /// Only run this part.
+    pub part: Option<u8>,
+    /// Overrides of the day's `Solution::PARAMS`.
+    pub params: Params,
+    /// Append timings to `history::path()`.
+    pub history: bool,
+    /// Compare timings against the history of this commit, or of the latest run with "last".
+    pub baseline: Option<String>,
+    /// Percentage a part may get slower than its baseline, `DEFAULT_THRESHOLD` if not set.
+    pub threshold: Option<f64>,
+    /// Count the allocations of every part, needs the `alloc-stats` feature.
+    pub alloc: bool,
+    /// Give up on parsing or a part after this long.
+    pub time_limit: Option<Duration>,
+}
+
+pub const DEFAULT_THRESHOLD: f64 = 10.0;
+
+fn parse_part(s: &str) -> Result<u8, String> {
+    match s {
+        "1" => Ok(1),
+        "2" => Ok(2),
+        _ => Err(format!("unknown part \"{}\", expected 1 or 2", s)),
+    }
+}
+
+/// Parses `--time-limit`, in seconds.
+fn parse_time_limit(s: &str) -> Result<Duration, String> {
+    match s.parse::<f64>() {
+        Ok(seconds) if seconds > 0.0 && seconds.is_finite() => Ok(Duration::from_secs_f64(seconds)),
+        _ => Err(format!("invalid time limit \"{}\", expected seconds", s)),
+    }
+}
+
+fn parse_alloc(args: &mut pico_args::Arguments) -> Result<bool, pico_args::Error> {
+    match args.contains("--alloc") {
+        true if !memory::ENABLED => Err(pico_args::Error::Utf8ArgumentParsingFailed {
+            value: "--alloc".to_string(),
+            cause: "needs the `alloc-stats` feature, e.g. `cargo all --features alloc-stats -- --alloc`".to_string(),
+        }),
+        alloc => Ok(alloc),
+    }
+}
+
+/// Days picked on the command line, e.g. `5`, `1,5,12`, `3..7` or `20..`.
+/// Ranges follow Rust, so `3..7` stops at day 6 and `3..=7` includes day 7.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct DaySelection(Vec<RangeInclusive<u8>>);
+
+impl DaySelection {
+    pub fn contains(&self, day: u8) -> bool {
+        self.0.iter().any(|range| range.contains(&day))
+    }
+}
+
+impl Default for DaySelection {
+    fn default() -> Self {
+        Self(vec![1..=25])
+    }
+}
+
+fn parse_day(s: &str) -> Result<u8, String> {
+    match s.parse() {
+        Ok(day @ 1..=25) => Ok(day),
+        _ => Err(format!("invalid day \"{}\", expected 1 to 25", s)),
+    }
+}
+
+impl FromStr for DaySelection {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.split(',')
+            .map(|item| {
+                let item = item.trim();
+                let (start, end) = match item.split_once("..") {
+                    None => return parse_day(item).map(|day| day..=day),
+                    Some(bounds) => bounds,
+                };
+
+                let start = match start {
+                    "" => 1,
+                    start => parse_day(start)?,
+                };
+                let end = match end.strip_prefix('=') {
+                    Some(end) => parse_day(end)?,
+                    None if end.is_empty() => 25,
+                    // An exclusive end is one past the last day, so `20..26` runs up to day 25.
+                    None => match end.parse::<u8>() {
+                        Ok(end @ 2..=26) => end - 1,
+                        _ => return Err(format!("invalid end \"{}\", expected 2 to 26", end)),
+                    },
+                };
+
+                match start <= end {
+                    true => Ok(start..=end),
+                    false => Err(format!("empty day range \"{}\"", item)),
+                }
+            })
+            .collect::<Result<_, _>>()
+            .map(Self)
+    }
+}
+
+impl RunOptions {
+    pub fn from_args(args: &mut pico_args::Arguments) -> Result<Self, pico_args::Error> {
+        Ok(Self {
+            format: args
+                .opt_value_from_str("--format")?
+                .unwrap_or(Format::Human),
+            bench: BenchOptions::from_args(args)?,
+            verify: args.contains("--verify"),
+            record: args.contains("--record"),
+            inputs_dir: args.opt_value_from_str("--inputs-dir")?,
+            input: args.opt_value_from_str("--input")?,
+            part: args.opt_value_from_fn("--part", parse_part)?,
+            params: args
+                .values_from_fn("--param", params::parse_param)?
+                .into_iter()
+                .collect(),
+            history: args.contains("--history"),
+            baseline: args.opt_value_from_str("--baseline")?,
+            threshold: args.opt_value_from_str("--threshold")?,
+            alloc: parse_alloc(args)?,
+            time_limit: args.opt_value_from_fn("--time-limit", parse_time_limit)?,
+        })
+    }
+
+    /// Input of `day`, honouring `--input` and `--inputs-dir`.
+    pub fn input_source(&self, year: u16, day: u8) -> InputSource {
+        match (&self.input, &self.inputs_dir) {
+            (Some(input), _) => input.clone(),
+            (None, Some(dir)) => InputSource::Path(crate::day_path(dir, year, day)),
+            (None, None) => InputSource::Path(crate::file_path("inputs", year, day)),
+        }
+    }
+
+    /// Whether `part` was selected with `--part`, or no part was selected.
+    pub fn runs_part(&self, part: u8) -> bool {
+        self.part.is_none_or(|selected| selected == part)
+    }
+
+    /// Parses the flags of the current process, exiting on invalid or unknown ones.
+    pub fn from_env() -> Self {
+        Self::from_env_with(|_| Ok(())).0
+    }
+
+    /// Like `from_env`, for binaries with flags of their own, which `extra` parses.
+    pub fn from_env_with<T>(
+        extra: impl FnOnce(&mut pico_args::Arguments) -> Result<T, pico_args::Error>,
+    ) -> (Self, T) {
+        let mut args = pico_args::Arguments::from_env();
+        let parsed =
+            Self::from_args(&mut args).and_then(|options| Ok((options, extra(&mut args)?)));
+
+        let parsed = match parsed {
+            Ok(parsed) => parsed,
+            Err(e) => {
+                eprintln!("{}", e);
+                process::exit(1);
+            }
+        };
+
+        if let Some(arg) = args.finish().first() {
+            eprintln!("Unexpected argument {:?}.", arg);
+            process::exit(1);
+        }
+
+        parsed
+    }
+}
+
+pub fn run_part(
+    year: u16,
+    day: u8,
+    part: u8,
+    solver: impl Fn() -> Option<Answer>,
+    options: &RunOptions,
+) -> PartResult {
+    let measurement = options.alloc.then(|| memory::ALLOCATOR.start());
+    let timer = Instant::now();
+    let result = catch_silently(&solver);
+    let elapsed = timer.elapsed();
+    let alloc = measurement.map(|start| memory::ALLOCATOR.stop(&start));
+
+    let (answer, status, message) = match result {
+        Ok(Some(answer)) => (Some(answer.to_string()), Status::Solved, None),
+        Ok(None) => (None, Status::Unsolved, None),
+        Err(payload) => (None, Status::Panicked, Some(panic_message(&*payload))),
+    };
+
+    let bench = match (status, &options.bench) {
+        (Status::Solved, Some(bench_options)) => bench::bench_part(&solver, bench_options),
+        _ => None,
+    };
+
+    PartResult {
+        year,
+        day,
+        part,
+        answer,
+        elapsed,
+        status,
+        parse: Duration::ZERO,
+        message,
+        expected: None,
+        bench,
+        parse_bench: None,
+        alloc,
+        parallel: false,
+    }
+}
+
+thread_local! {
+    static SILENCED: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Like `panic::catch_unwind`, but without the default hook printing the panic to stderr,
+/// since the message ends up in the result. Panics outside of it are printed as usual.
+pub(crate) fn catch_silently<R>(f: impl FnOnce() -> R) -> Result<R, Box<dyn Any + Send>> {
+    static INSTALL: Once = Once::new();
+    INSTALL.call_once(|| {
+        let previous = panic::take_hook();
+        panic::set_hook(Box::new(move |info| {
+            if !SILENCED.with(Cell::get) {
+                previous(info);
+            }
+        }));
+    });
+
+    let silenced = SILENCED.with(|silenced| silenced.replace(true));
+    let result = panic::catch_unwind(AssertUnwindSafe(f));
+    SILENCED.with(|current| current.set(silenced));
+    result
+}
+
+fn panic_message(payload: &(dyn Any + Send)) -> String {
+    match (
+        payload.downcast_ref::<&str>(),
+        payload.downcast_ref::<String>(),
+    ) {
+        (Some(message), _) => message.to_string(),
+        (_, Some(message)) => message.clone(),
+        _ => "unknown panic payload".to_string(),
+    }
+}
+
+/// Steps of `solve_parts`, which reports them as soon as they are done.
+enum Progress {
+    Parsed(Duration),
+    Part(Box<PartResult>),
+}
+
+/// Parses `input` once and runs the selected parts of `S` on it.
+fn solve_parts<S: Solution>(
+    year: u16,
+    day: u8,
+    input: &str,
+    options: &RunOptions,
+    mut report: impl FnMut(Progress),
+) {
+    let parts = [1, 2].into_iter().filter(|part| options.runs_part(*part));
+
+    let mut params = S::params();
+    params.extend(&options.params);
+
+    let timer = Instant::now();
+    let parsed = catch_silently(|| S::parse_with(input, &params));
+    let parse = timer.elapsed();
+
+    let parsed = match parsed {
+        Ok(parsed) => parsed,
+        Err(payload) => {
+            let message = panic_message(&*payload);
+            report(Progress::Parsed(parse));
+            for part in parts {
+                report(Progress::Part(Box::new(PartResult {
+                    parse,
+                    ..PartResult::panicked(year, day, part, message.clone())
+                })));
+            }
+            return;
+        }
+    };
+
+    let parse_bench = options.bench.and_then(|bench_options| {
+        bench::bench_part(|| Some(S::parse_with(input, &params)), &bench_options)
+    });
+    report(Progress::Parsed(parse));
+
+    for part in parts {
+        let result = match part {
+            1 => run_part(
+                year,
+                day,
+                1,
+                || S::part_one(&parsed).map(|answer| Box::new(answer) as Answer),
+                options,
+            ),
+            _ => run_part(
+                year,
+                day,
+                2,
+                || S::part_two(&parsed).map(|answer| Box::new(answer) as Answer),
+                options,
+            ),
+        };
+
+        report(Progress::Part(Box::new(PartResult {
+            parse,
+            parse_bench,
+            ..result
+        })));
+    }
+}
+
+/// Parses `input` once and runs the selected parts of `S` on it, giving up on steps that take
+/// longer than `--time-limit`.
+pub fn run_solution<S: Solution + 'static>(
+    year: u16,
+    day: u8,
+    input: &str,
+    options: &RunOptions,
+) -> Vec<PartResult> {
+    let Some(limit) = options.time_limit else {
+        let mut results = Vec::new();
+        solve_parts::<S>(year, day, input, options, |progress| {
+            if let Progress::Part(result) = progress {
+                results.push(*result);
+            }
+        });
+        return results;
+    };
+
+    // Threads can't be stopped, so a step that timed out keeps running in the background
+    // until the process exits.
+    let (sender, receiver) = mpsc::channel();
+    let worker_input = input.to_string();
+    let worker_options = options.clone();
+    thread::spawn(move || {
+        solve_parts::<S>(year, day, &worker_input, &worker_options, |progress| {
+            // Nobody is listening anymore once a step timed out.
+            let _ = sender.send(progress);
+        })
+    });
+
+    // Benchmarks run right after their step, so they get their budget on top.
+    let wait = limit + options.bench.map_or(Duration::ZERO, |bench| bench.budget);
+    let mut parse = None;
+    let mut results: Vec<PartResult> = Vec::new();
+
+    // Waiting starts when the previous step is received, which can be a bit after it finished,
+    // so steps are also held against the limit with the time they measured themselves.
+    let timed_out = loop {
+        match receiver.recv_timeout(wait) {
+            Ok(Progress::Parsed(elapsed)) if elapsed > limit => break true,
+            Ok(Progress::Parsed(elapsed)) => parse = Some(elapsed),
+            Ok(Progress::Part(result)) if result.elapsed > limit => break true,
+            Ok(Progress::Part(result)) => results.push(*result),
+            Err(RecvTimeoutError::Timeout) => break true,
+            Err(RecvTimeoutError::Disconnected) => break false,
+        }
+    };
+
+    // The first missing step is the one that ran out of time, the ones after it never started.
+    let mut running = true;
+    let missing = [1, 2]
+        .into_iter()
+        .filter(|part| options.runs_part(*part))
+        .filter(|part| results.iter().all(|result| result.part != *part))
+        .collect::<Vec<_>>();
+
+    for part in missing {
+        let result = match (timed_out, parse) {
+            (false, _) => PartResult::panicked(year, day, part, "solver stopped".to_string()),
+            (true, None) => PartResult {
+                parse: limit,
+                ..PartResult::timed_out(year, day, part, format!("parse took over {:.2?}", limit))
+            },
+            (true, Some(parse)) if running => PartResult {
+                parse,
+                elapsed: limit,
+                ..PartResult::timed_out(year, day, part, format!("took over {:.2?}", limit))
+            },
+            (true, Some(parse)) => PartResult {
+                parse,
+                ..PartResult::timed_out(year, day, part, "never started".to_string())
+            },
+        };
+
+        running = false;
+        results.push(result);
+    }
+
+    results
+}
+
+pub fn run_day(day: &Day, input: &str, options: &RunOptions) -> Vec<PartResult> {
+    let mut results = (day.solve)(day.year, day.day, input, options);
+
+    if !options.verify && !options.record {
+        return results;
+    }
+
+    let mut answers = match Answers::load(day.year, day.day) {
+        Ok(answers) => answers,
+        Err(e) => {
+            eprintln!(
+                "Failed to read answers file \"{}\": {}",
+                Answers::path(day.year, day.day).display(),
+                e
+            );
+            return results;
+        }
+    };
+
+    if options.verify {
+        answers.verify(&mut results);
+    }
+
+    if options.record {
+        let recorded = answers.record(&results);
+        if !recorded.is_empty() {
+            match answers.save(day.year, day.day) {
+                Ok(_) => eprintln!(
+                    "Recorded {} day {} part(s) {:?} as accepted.",
+                    day.year, day.day, recorded
+                ),
+                Err(e) => eprintln!("Failed to write answers file: {}", e),
+            }
+        }
+    }
+
+    results
+}
+
+/// Whether any part didn't reproduce its accepted answer, including parts that failed to solve.
+pub fn has_wrong_answers(results: &[PartResult]) -> bool {
+    results.iter().any(PartResult::is_mismatch)
+}
+
+/// Prints the parse time of a day followed by its parts.
+pub fn print_day(results: &[PartResult]) {
+    if let Some(result) = results.first() {
+        if !result.parse.is_zero() {
+            println!("🎄 {}Parse{} 🎄", ANSI_BOLD, ANSI_RESET);
+            println!(
+                "{}(elapsed: {:.2?}){}",
+                ANSI_ITALIC, result.parse, ANSI_RESET
+            );
+
+            if let Some(stats) = &result.parse_bench {
+                println!("{}(bench: {}){}", ANSI_ITALIC, stats, ANSI_RESET);
+            }
+        }
+    }
+
+    results.iter().for_each(print_part);
+}
+
+pub fn print_part(result: &PartResult) {
+    println!("🎄 {}Part {}{} 🎄", ANSI_BOLD, result.part, ANSI_RESET);
+    match (&result.answer, result.status) {
+        (Some(answer), _) => {
+            let mode = match result.parallel {
+                true => ", in parallel",
+                false => "",
+            };
+            let alloc = match &result.alloc {
+                Some(stats) => format!(", {}", stats),
+                None => String::new(),
+            };
+            println!(
+                "{} {}(elapsed: {:.2?}{}{}){}",
+                answer, ANSI_ITALIC, result.elapsed, mode, alloc, ANSI_RESET
+            );
+        }
+        (None, Status::Panicked | Status::TimedOut) => {
+            let status = match result.status {
+                Status::Panicked => "panicked",
+                _ => "timed out",
+            };
+            match &result.message {
+                Some(message) => println!("{}: {}", status, message),
+                None => println!("{}.", status),
+            }
+        }
+        (None, _) => {
+            println!("not solved.")
+        }
+    }
+
+    if let (true, Some(expected)) = (result.is_mismatch(), &result.expected) {
+        match result.status {
+            Status::Wrong => println!("wrong answer, expected: {}", expected),
+            _ => println!("expected: {}", expected),
+        }
+    }
+
+    if let Some(stats) = &result.bench {
+        println!("{}(bench: {}){}", ANSI_ITALIC, stats, ANSI_RESET);
+    }
+}
+
+fn csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+/// Prints `results` in a machine-readable `format`. Human output is printed while running instead.
+pub fn print_results(format: Format, results: &[PartResult]) {
+    match format {
+        Format::Human => print_day(results),
+        Format::Json => {
+            println!("{}", serde_json::to_string_pretty(results).unwrap());
+        }
+        Format::Csv => {
+            println!("year,day,part,answer,elapsed_ns,parse_ns,status,expected,samples,min_ns,median_ns,mean_ns,p95_ns,std_dev_ns,parallel,allocations,alloc_bytes,peak_bytes,message");
+            for result in results {
+                let bench = match &result.bench {
+                    Some(stats) => format!(
+                        "{},{},{},{},{},{}",
+                        stats.samples,
+                        stats.min.as_nanos(),
+                        stats.median.as_nanos(),
+                        stats.mean.as_nanos(),
+                        stats.p95.as_nanos(),
+                        stats.std_dev.as_nanos()
+                    ),
+                    None => ",,,,,".to_string(),
+                };
+
+                let alloc = match &result.alloc {
+                    Some(stats) => {
+                        format!("{},{},{}", stats.allocations, stats.bytes, stats.peak_bytes)
+                    }
+                    None => ",,".to_string(),
+                };
+
+                println!(
+                    "{},{},{},{},{},{},{},{},{},{},{},{}",
+                    result.year,
+                    result.day,
+                    result.part,
+                    csv_field(result.answer.as_deref().unwrap_or("")),
+                    result.elapsed.as_nanos(),
+                    result.parse.as_nanos(),
+                    result.status,
+                    csv_field(result.expected.as_deref().unwrap_or("")),
+                    bench,
+                    result.parallel,
+                    alloc,
+                    csv_field(result.message.as_deref().unwrap_or(""))
+                );
+            }
+        }
+    }
+}
+
+fn print_comparisons(baseline: &str, comparisons: &[Comparison], threshold: f64) {
+    println!("---------------");
+    println!("{}Compared to {}:{}", ANSI_BOLD, baseline, ANSI_RESET);
+    println!(
+        "{:>7} | {:>4} | {:>10} | {:>10} | {:>8}",
+        "Day", "Part", "Baseline", "Current", "Change"
+    );
+
+    for comparison in comparisons {
+        let flag = match comparison.is_regression(threshold) {
+            true => format!(" {}slower{}", ANSI_BOLD, ANSI_RESET),
+            false => String::new(),
+        };
+
+        println!(
+            "{:>7} | {:>4} | {:>10} | {:>10} | {:>+7.1}%{}",
+            format!("{}/{:02}", comparison.year, comparison.day),
+            comparison.part,
+            format!("{:.2?}", comparison.baseline),
+            format!("{:.2?}", comparison.current),
+            comparison.change(),
+            flag
+        );
+    }
+}
+
+/// Compares `results` with `--baseline` and appends them to the history with `--history`.
+/// Returns whether a part got slower than `--threshold` allows.
+pub fn track_history(results: &[PartResult], options: &RunOptions) -> bool {
+    if options.baseline.is_none() && !options.history {
+        return false;
+    }
+
+    let path = history::path();
+    let entries = Entry::from_results(results, &history::current_commit(), history::now());
+    let mut regressed = false;
+
+    if let Some(baseline) = &options.baseline {
+        match history::load(&path) {
+            Ok(recorded) => {
+                let comparisons = history::compare(&recorded, baseline, &entries);
+                let threshold = options.threshold.unwrap_or(DEFAULT_THRESHOLD);
+                let regressions = comparisons
+                    .iter()
+                    .filter(|comparison| comparison.is_regression(threshold))
+                    .count();
+
+                // Machine-readable formats are meant to be piped, so only the summary is shown.
+                if options.format == Format::Human && !comparisons.is_empty() {
+                    print_comparisons(baseline, &comparisons, threshold);
+                }
+
+                if comparisons.is_empty() {
+                    eprintln!("No timings recorded for baseline \"{}\".", baseline);
+                } else if regressions > 0 {
+                    eprintln!(
+                        "{} part(s) got more than {}% slower than \"{}\".",
+                        regressions, threshold, baseline
+                    );
+                    regressed = true;
+                }
+            }
+            Err(e) => eprintln!("Failed to read history file \"{}\": {}", path.display(), e),
+        }
+    }
+
+    if options.history {
+        if let Err(e) = history::append(&path, &entries) {
+            eprintln!("Failed to write history file \"{}\": {}", path.display(), e);
+        }
+    }
+
+    regressed
+}
+
+/// Entry point of the per-day binaries in `src/bin`.
+pub fn solve_day(year: u16, day: u8) {
+    let options = RunOptions::from_env();
+
+    let entry = match days::get(year, day) {
+        Some(entry) => entry,
+        None => {
+            eprintln!(
+                "Day {} of {} is not registered in `src/days/y{}/mod.rs`.",
+                day, year, year
+            );
+            process::exit(1);
+        }
+    };
+
+    if let Err(e) = Params::check(entry.params, &options.params) {
+        eprintln!("Day {}: {}.", day, e);
+        process::exit(1);
+    }
+
+    let input = match options.input_source(year, day).read() {
+        Ok(input) => input,
+        Err(e) => {
+            eprintln!("Failed to read the input of {} day {}: {}", year, day, e);
+            process::exit(1);
+        }
+    };
+
+    let results = run_day(entry, &input, &options);
+    print_results(options.format, &results);
+    let regressed = track_history(&results, &options);
+
+    if has_wrong_answers(&results) || regressed {
+        process::exit(1);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Example;
+
+    impl Solution for Example {
+        type Input<'a> = Vec<&'a str>;
+        type AnswerOne = String;
+        type AnswerTwo = u32;
+
+        fn parse(input: &str) -> Self::Input<'_> {
+            assert!(!input.is_empty(), "empty input");
+            input.lines().collect()
+        }
+
+        fn part_one(input: &Self::Input<'_>) -> Option<String> {
+            input.first().map(|line| line.to_string())
+        }
+
+        fn part_two(input: &Self::Input<'_>) -> Option<u32> {
+            assert_eq!(input.len(), 1, "bad input");
+            None
+        }
+    }
+
+    #[test]
+    fn test_run_solution() {
+        let options = RunOptions::default();
+
+        let results = run_solution::<Example>(2022, 3, "a, \"b\"", &options);
+        assert_eq!(results[0].status, Status::Solved);
+        assert_eq!(results[0].answer.as_deref(), Some("a, \"b\""));
+        assert_eq!(results[1].status, Status::Unsolved);
+
+        let results = run_solution::<Example>(2022, 3, "a\nb", &options);
+        assert_eq!(results[0].status, Status::Solved);
+        assert_eq!(results[1].status, Status::Panicked);
+
+        assert!(results[1].message.as_deref().unwrap().contains("bad input"));
+
+        let results = run_solution::<Example>(2022, 3, "", &options);
+        assert_eq!(results[0].status, Status::Panicked);
+        assert_eq!(results[1].status, Status::Panicked);
+        assert_eq!(results[1].message.as_deref(), Some("empty input"));
+    }
+
+    struct Slow;
+
+    impl Solution for Slow {
+        type Input<'a> = u64;
+        type AnswerOne = u64;
+        type AnswerTwo = u64;
+
+        fn parse(input: &str) -> Self::Input<'_> {
+            input.parse().unwrap()
+        }
+
+        fn part_one(input: &Self::Input<'_>) -> Option<u64> {
+            thread::sleep(Duration::from_millis(*input));
+            Some(*input)
+        }
+
+        fn part_two(input: &Self::Input<'_>) -> Option<u64> {
+            Some(*input)
+        }
+    }
+
+    #[test]
+    fn test_time_limit() {
+        let mut args = pico_args::Arguments::from_vec(vec!["--time-limit".into(), "0.2".into()]);
+        let options = RunOptions::from_args(&mut args).unwrap();
+
+        let results = run_solution::<Slow>(2022, 3, "0", &options);
+        assert_eq!(results[0].status, Status::Solved);
+        assert_eq!(results[1].answer.as_deref(), Some("0"));
+
+        let results = run_solution::<Slow>(2022, 3, "2000", &options);
+        assert_eq!(results[0].status, Status::TimedOut);
+        assert_eq!(results[0].elapsed, Duration::from_millis(200));
+        assert_eq!(results[1].status, Status::TimedOut);
+        assert_eq!(results[1].elapsed, Duration::ZERO);
+
+        let mut args = pico_args::Arguments::from_vec(vec!["--time-limit".into(), "0".into()]);
+        assert!(RunOptions::from_args(&mut args).is_err());
+    }
+
+    #[test]
+    fn test_part_selection() {
+        let mut args = pico_args::Arguments::from_vec(vec!["--part".into(), "2".into()]);
+        let options = RunOptions::from_args(&mut args).unwrap();
+
+        let results = run_solution::<Example>(2022, 3, "a\nb", &options);
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].part, 2);
+        assert_eq!(results[0].status, Status::Panicked);
+
+        let mut args = pico_args::Arguments::from_vec(vec!["--part".into(), "3".into()]);
+        assert!(RunOptions::from_args(&mut args).is_err());
+    }
+
+    #[test]
+    fn test_day_selection() {
+        let selection: DaySelection = "1,5, 12..14,20..=21,24..".parse().unwrap();
+        let days: Vec<u8> = (1..=25).filter(|day| selection.contains(*day)).collect();
+        assert_eq!(days, [1, 5, 12, 13, 20, 21, 24, 25]);
+
+        let selection: DaySelection = "..=3".parse().unwrap();
+        assert!(selection.contains(3) && !selection.contains(4));
+
+        let selection: DaySelection = "20..26".parse().unwrap();
+        let days: Vec<u8> = (1..=25).filter(|day| selection.contains(*day)).collect();
+        assert_eq!(days, [20, 21, 22, 23, 24, 25]);
+        assert!("20..27".parse::<DaySelection>().is_err());
+        assert!("20..=26".parse::<DaySelection>().is_err());
+
+        assert!("0".parse::<DaySelection>().is_err());
+        assert!("26".parse::<DaySelection>().is_err());
+        assert!("7..3".parse::<DaySelection>().is_err());
+        assert!("3..3".parse::<DaySelection>().is_err());
+        assert!("1,,2".parse::<DaySelection>().is_err());
+    }
+
+    #[test]
+    fn test_json_record() {
+        let mut result =
+            run_solution::<Example>(2022, 3, "a, \"b\"", &RunOptions::default()).remove(0);
+        result.elapsed = Duration::from_nanos(1500);
+        result.parse = Duration::from_nanos(500);
+
+        assert_eq!(
+            serde_json::to_string(&result).unwrap(),
+            r#"{"year":2022,"day":3,"part":1,"answer":"a, \"b\"","elapsed_ns":1500,"status":"solved","parse_ns":500}"#
+        );
+
+        result.parallel = true;
+        assert!(serde_json::to_string(&result)
+            .unwrap()
+            .ends_with(r#""parse_ns":500,"parallel":true}"#));
+    }
+
+    #[test]
+    fn test_csv_field() {
+        assert_eq!(csv_field("24000"), "24000");
+        assert_eq!(csv_field("a, \"b\""), "\"a, \"\"b\"\"\"");
+        assert_eq!(csv_field("#.\n.#"), "\"#.\n.#\"");
+    }
+}
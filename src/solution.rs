@@ -0,0 +1,61 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use crate::Params;
+use std::fmt::Display;
+
+/// A day's solution. `parse` runs once and its result is shared by both parts.
+///
+/// Days that work on the raw input directly can use `type Input<'a> = &'a str`.
+pub trait Solution {
+    type Input<'a>;
+    type AnswerOne: Display + 'static;
+    type AnswerTwo: Display + 'static;
+
+    /// Puzzle parameters with their defaults for the real input, see `Params`.
+    const PARAMS: &'static [(&'static str, &'static str)] = &[];
+
+    fn parse(input: &str) -> Self::Input<'_>;
+
+    /// Parses `input` with `params`, which include the defaults of `PARAMS`.
+    ///
+    /// Days with parameters override this and implement `parse` as `parse_with(input, &Self::params())`.
+    fn parse_with<'a>(input: &'a str, params: &Params) -> Self::Input<'a> {
+        let _ = params;
+        Self::parse(input)
+    }
+
+    /// The defaults of `PARAMS`.
+    fn params() -> Params {
+        Params::new(Self::PARAMS)
+    }
+
+    fn part_one(input: &Self::Input<'_>) -> Option<Self::AnswerOne>;
+
+    fn part_two(input: &Self::Input<'_>) -> Option<Self::AnswerTwo>;
+
+    /// Parses `input` and solves part one, mostly useful in tests.
+    fn solve_part_one(input: &str) -> Option<Self::AnswerOne> {
+        Self::part_one(&Self::parse(input))
+    }
+
+    /// Parses `input` and solves part two, mostly useful in tests.
+    fn solve_part_two(input: &str) -> Option<Self::AnswerTwo> {
+        Self::part_two(&Self::parse(input))
+    }
+
+    /// Like `solve_part_one`, with some of the parameters overridden.
+    fn solve_part_one_with(input: &str, overrides: &Params) -> Option<Self::AnswerOne> {
+        let mut params = Self::params();
+        params.extend(overrides);
+        Self::part_one(&Self::parse_with(input, &params))
+    }
+
+    /// Like `solve_part_two`, with some of the parameters overridden.
+    fn solve_part_two_with(input: &str, overrides: &Params) -> Option<Self::AnswerTwo> {
+        let mut params = Self::params();
+        params.extend(overrides);
+        Self::part_two(&Self::parse_with(input, &params))
+    }
+}
@@ -0,0 +1,3 @@
+fn main() {
+    advent_of_code::runner::solve_day({{year}}, {{day}});
+}
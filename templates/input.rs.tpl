@@ -0,0 +1,13 @@
+
+#[allow(dead_code)]
+pub struct Input {
+    lines: Vec<String>,
+}
+
+impl Input {
+    fn from_input(input: &str) -> Self {
+        Self {
+            lines: {{lines}},
+        }
+    }
+}
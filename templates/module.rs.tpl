@@ -0,0 +1,40 @@
+//! {{title}}
+//! https://adventofcode.com/{{year}}/day/{{day}}
+use crate::Solution;
+{{input_struct}}{{parsers}}
+pub struct Day{{padded}};
+
+impl Solution for Day{{padded}} {
+    type Input<'a> = {{input_type}};
+    type AnswerOne = {{answer_one}};
+    type AnswerTwo = {{answer_two}};
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        {{parse}}
+    }
+
+    fn part_one(_input: &Self::Input<'_>) -> Option<{{answer_one}}> {
+        None
+    }
+
+    fn part_two(_input: &Self::Input<'_>) -> Option<{{answer_two}}> {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let input = crate::read_file("examples", {{year}}, {{day}}).unwrap();
+        assert_eq!(Day{{padded}}::solve_part_one(&input), None);
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = crate::read_file("examples", {{year}}, {{day}}).unwrap();
+        assert_eq!(Day{{padded}}::solve_part_two(&input), None);
+    }
+}
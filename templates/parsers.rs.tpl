@@ -0,0 +1,13 @@
+
+mod parsers {
+    use nom::character::complete::{line_ending, not_line_ending};
+    use nom::multi::separated_list1;
+
+    pub fn parse_input(input: &str) -> nom::IResult<&str, Vec<&str>> {
+        separated_list1(line_ending, parse_line)(input)
+    }
+
+    fn parse_line(input: &str) -> nom::IResult<&str, &str> {
+        not_line_ending(input)
+    }
+}
@@ -0,0 +1,8 @@
+/*
+ * Every solved day of {{year}} is registered here.
+ * Add a `N => dayNN::DayNN` line to `register!` after creating `./dayNN.rs`.
+ */
+pub const YEAR: u16 = {{year}};
+
+register! {
+}